@@ -1,11 +1,12 @@
 #![allow(clippy::needless_doctest_main)]
+#![cfg_attr(not(feature = "std"), no_std)]
 //! # Bytey
 //! Bytey provides a convenient and easy to use byte storage.
 //! # Installation
 //! To start using this crate all you have to do is add it to your **Cargo.toml**:
 //! ```toml
 //! [dependencies]
-//! bytey = "0.4.0"
+//! bytey = "0.5.0"
 //! ```
 //! # Usage
 //!```
@@ -50,7 +51,7 @@
 pub use bytey_byte_buffer::byte_buffer::ByteBuffer;
 
 #[doc(inline)]
-pub use bytey_byte_buffer::error::{ByteBufferError, Result};
+pub use bytey_byte_buffer::error::{ByteBufferError, ErrorContext, ErrorPath, PathSegment, Result};
 
 #[doc(inline)]
 pub use bytey_byte_buffer::byte_buffer_write::{self, ByteBufferWrite};
@@ -58,5 +59,42 @@ pub use bytey_byte_buffer::byte_buffer_write::{self, ByteBufferWrite};
 #[doc(inline)]
 pub use bytey_byte_buffer::byte_buffer_read::{self, ByteBufferRead};
 
+#[doc(inline)]
+pub use bytey_byte_buffer::byte_buffer_read_ref::{self, ByteBufferReadRef};
+
+#[doc(inline)]
+pub use bytey_byte_buffer::byte_buffer_ref::ByteBufferRef;
+
+#[doc(inline)]
+pub use bytey_byte_buffer::fixed_buffer::{ArrayByteBuffer, SliceBuffer};
+
+#[doc(inline)]
+pub use bytey_byte_buffer::varint::{self, VarI32, VarI64, VarU32, VarU64};
+
+#[doc(inline)]
+pub use bytey_byte_buffer::length_prefix::{self, Length, LengthPrefix};
+
+#[doc(inline)]
+pub use bytey_byte_buffer::decode_limits::{self, DecodeLimits};
+
+#[doc(inline)]
+pub use bytey_byte_buffer::no_uninit::NoUninit;
+
+#[doc(inline)]
+pub use bytey_byte_buffer::stream::{self, Sink, Source};
+
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use bytey_byte_buffer::stream::{IoSink, IoSource};
+
+#[cfg(feature = "bytes")]
+#[doc(inline)]
+pub use bytey_byte_buffer::bytes::{self, BufSink, BufSource};
+
+#[cfg(feature = "tokio")]
+#[doc(inline)]
+pub use bytey_byte_buffer::codec::{self, ByteyCodec};
+
 pub use bytey_derive::ByteBufferRead;
+pub use bytey_derive::ByteBufferReadRef;
 pub use bytey_derive::ByteBufferWrite;
@@ -1,12 +1,15 @@
 use crate::byte_buffer_read::ByteBufferRead;
+use crate::byte_buffer_ref::ByteBufferRef;
 use crate::byte_buffer_write::ByteBufferWrite;
-use std::{
+use ::alloc::{
     alloc::{self, Layout},
-    cmp::max,
-    ptr, slice,
+    vec::Vec,
 };
+use core::{borrow::Borrow, cmp::max, ops::Deref, ptr, slice};
 
 use crate::error::{ByteBufferError, Result};
+use crate::decode_limits::DecodeLimits;
+use crate::length_prefix::LengthPrefix;
 use core::ptr::NonNull;
 /// A resizeable buffer to store data in.
 ///
@@ -38,6 +41,9 @@ pub struct ByteBuffer {
     length: usize,
     cursor: usize,
     pointer: NonNull<u8>,
+    length_prefix: LengthPrefix,
+    canonical: bool,
+    decode_limits: DecodeLimits,
 }
 
 /// `ByteBuffer` are `Send` Becuase `u8` is `Send` because the data they
@@ -116,6 +122,9 @@ impl ByteBuffer {
             length: 0,
             cursor: 0,
             pointer,
+            length_prefix: LengthPrefix::default(),
+            canonical: false,
+            decode_limits: DecodeLimits::new(),
         })
     }
 
@@ -444,11 +453,12 @@ impl ByteBuffer {
     /// println!("{:?}", buffer.read_slice(4));
     /// ```
     pub fn read_slice(&mut self, size: usize) -> Result<&[u8]> {
-        if self.cursor + size > self.length {
+        // A forged length can make the end overflow, which has to fail the bounds check as well.
+        if self.cursor.checked_add(size).is_none_or(|end| end > self.length) {
             return Err(ByteBufferError::ReadOutOfBounds {
                 length: self.length,
                 start: self.cursor,
-                end: self.cursor + size,
+                end: self.cursor.saturating_add(size),
             });
         }
 
@@ -527,6 +537,133 @@ impl ByteBuffer {
         T::read_from_bytey_buffer_be(self)
     }
 
+    /// Runs the given closure and restores the cursor position if it returns an error.
+    ///
+    /// # Behaviour
+    /// - On success the cursor position is left where the closure moved it.
+    /// - On error the cursor position is moved back to where it was before the closure ran.
+    ///   Bytes written by the closure are not removed.
+    ///
+    /// # Errors
+    /// Returns the error of the closure.
+    ///
+    /// # Examples
+    /// ```
+    /// use bytey_byte_buffer::byte_buffer::ByteBuffer;
+    ///
+    /// let mut buffer = ByteBuffer::new().unwrap();
+    ///
+    /// buffer.write(1u16);
+    /// buffer.move_cursor(0);
+    ///
+    /// let result = buffer.transaction(|b| Ok((b.read::<u16>()?, b.read::<u16>()?)));
+    ///
+    /// assert!(result.is_err());
+    /// assert_eq!(buffer.cursor(), 0);
+    /// ```
+    pub fn transaction<R>(&mut self, f: impl FnOnce(&mut Self) -> Result<R>) -> Result<R> {
+        let cursor = self.cursor;
+        let result = f(self);
+
+        if result.is_err() {
+            // The closure can only shrink the length with truncate, which keeps the cursor in bounds.
+            self.cursor = cursor.min(self.length);
+        }
+
+        result
+    }
+
+    /// Reads a value of type T like [`read`](Self::read), but restores the cursor position if the read fails.
+    ///
+    /// # Errors
+    /// See [`read_slice`](Self::read_slice).
+    ///
+    /// # Examples
+    /// ```
+    /// use bytey_byte_buffer::byte_buffer::ByteBuffer;
+    ///
+    /// let mut buffer = ByteBuffer::new().unwrap();
+    ///
+    /// buffer.write(1u16);
+    /// buffer.write(2u8);
+    /// buffer.move_cursor(0);
+    ///
+    /// assert!(buffer.try_read::<(u16, u16)>().is_err());
+    /// assert_eq!(buffer.cursor(), 0);
+    /// assert_eq!(buffer.try_read::<(u16, u8)>().unwrap(), (1, 2));
+    /// ```
+    pub fn try_read<T: ByteBufferRead>(&mut self) -> Result<T> {
+        self.transaction(|buffer| buffer.read::<T>())
+    }
+
+    /// Reads a value of type T like [`read_le`](Self::read_le), but restores the cursor position if the read fails.
+    ///
+    /// # Errors
+    /// See [`read_slice`](Self::read_slice).
+    pub fn try_read_le<T: ByteBufferRead>(&mut self) -> Result<T> {
+        self.transaction(|buffer| buffer.read_le::<T>())
+    }
+
+    /// Reads a value of type T like [`read_be`](Self::read_be), but restores the cursor position if the read fails.
+    ///
+    /// # Errors
+    /// See [`read_slice`](Self::read_slice).
+    pub fn try_read_be<T: ByteBufferRead>(&mut self) -> Result<T> {
+        self.transaction(|buffer| buffer.read_be::<T>())
+    }
+
+    /// Reads a value of type T like [`read`](Self::read) without moving the cursor position.
+    ///
+    /// # Errors
+    /// See [`read_slice`](Self::read_slice).
+    ///
+    /// # Examples
+    /// ```
+    /// use bytey_byte_buffer::byte_buffer::ByteBuffer;
+    ///
+    /// let mut buffer = ByteBuffer::new().unwrap();
+    ///
+    /// buffer.write(7u32);
+    /// buffer.move_cursor(0);
+    ///
+    /// assert_eq!(buffer.peek::<u32>().unwrap(), 7);
+    /// assert_eq!(buffer.cursor(), 0);
+    /// ```
+    pub fn peek<T: ByteBufferRead>(&mut self) -> Result<T> {
+        let cursor = self.cursor;
+        let result = self.read::<T>();
+
+        self.cursor = cursor;
+
+        result
+    }
+
+    /// Reads a value of type T like [`read_le`](Self::read_le) without moving the cursor position.
+    ///
+    /// # Errors
+    /// See [`read_slice`](Self::read_slice).
+    pub fn peek_le<T: ByteBufferRead>(&mut self) -> Result<T> {
+        let cursor = self.cursor;
+        let result = self.read_le::<T>();
+
+        self.cursor = cursor;
+
+        result
+    }
+
+    /// Reads a value of type T like [`read_be`](Self::read_be) without moving the cursor position.
+    ///
+    /// # Errors
+    /// See [`read_slice`](Self::read_slice).
+    pub fn peek_be<T: ByteBufferRead>(&mut self) -> Result<T> {
+        let cursor = self.cursor;
+        let result = self.read_be::<T>();
+
+        self.cursor = cursor;
+
+        result
+    }
+
     /// Moves the current cursor position **without safety checks**.
     ///
     /// # Safety
@@ -591,7 +728,7 @@ impl ByteBuffer {
     ///
     /// buffer.write(&value);
     ///
-    /// buffer.move_cursor();
+    /// buffer.move_cursor(0);
     /// buffer.move_cursor_to_end();
     /// ```
     pub fn move_cursor_to_end(&mut self) -> &mut Self {
@@ -610,8 +747,7 @@ impl ByteBuffer {
     ///
     /// buffer.write(&value);
     ///
-    /// buffer.move_cursor();
-    /// buffer.move_cursor_to_end();
+    /// buffer.move_cursor_to_start();
     /// ```
     pub fn move_cursor_to_start(&mut self) -> &mut Self {
         self.cursor = 0;
@@ -655,6 +791,92 @@ impl ByteBuffer {
         Ok(self)
     }
 
+    /// Removes the bytes before the cursor without reallocating.
+    ///
+    /// # Behaviour
+    /// The bytes from the cursor up to the length are moved to the start of the buffer,
+    /// the length is reduced by the old cursor position and the cursor is set to 0.
+    /// The capacity of the buffer stays the same.
+    ///
+    /// # Examples
+    /// ```
+    /// use bytey_byte_buffer::byte_buffer::ByteBuffer;
+    ///
+    /// let mut buffer = ByteBuffer::new().unwrap();
+    ///
+    /// buffer.write_slice(&[1, 2, 3, 4]).unwrap();
+    /// buffer.move_cursor(3).unwrap();
+    /// buffer.compact();
+    ///
+    /// assert_eq!(buffer.length(), 1);
+    /// assert_eq!(buffer.read::<u8>().unwrap(), 4);
+    /// ```
+    pub fn compact(&mut self) -> &mut Self {
+        if self.cursor > 0 {
+            unsafe {
+                ptr::copy(
+                    self.pointer.as_ptr().add(self.cursor),
+                    self.pointer.as_ptr(),
+                    self.length - self.cursor,
+                );
+            }
+
+            self.length -= self.cursor;
+            self.cursor = 0;
+        }
+
+        self
+    }
+
+    /// Sets the length without any checks.
+    ///
+    /// # Safety
+    /// The length can't exceed the capacity and every byte up to the length has to be initialized.
+    pub(crate) unsafe fn set_length(&mut self, length: usize) {
+        self.length = length;
+    }
+
+    /// Returns the bytes from the cursor up to the capacity, the bytes after the length are uninitialized.
+    #[cfg(feature = "bytes")]
+    pub(crate) fn spare_mut(&mut self) -> &mut [core::mem::MaybeUninit<u8>] {
+        unsafe {
+            slice::from_raw_parts_mut(
+                self.pointer.as_ptr().add(self.cursor) as *mut core::mem::MaybeUninit<u8>,
+                self.cap - self.cursor,
+            )
+        }
+    }
+
+    /// Takes over the allocation of the vector, the cursor is set to 0.
+    pub(crate) fn from_vec(vec: Vec<u8>) -> Self {
+        let mut vec = core::mem::ManuallyDrop::new(vec);
+
+        // A ByteBuffer always has an allocation.
+        if vec.capacity() == 0 {
+            vec.reserve(Self::MIN_SIZE);
+        }
+
+        Self {
+            cap: vec.capacity(),
+            length: vec.len(),
+            cursor: 0,
+            // SAFETY: the capacity is not 0, so the vector has allocated.
+            pointer: unsafe { NonNull::new_unchecked(vec.as_mut_ptr()) },
+            length_prefix: LengthPrefix::default(),
+            canonical: false,
+            decode_limits: DecodeLimits::new(),
+        }
+    }
+
+    /// Hands the allocation over to a vector of the bytes up to the length.
+    pub(crate) fn into_vec(self) -> Vec<u8> {
+        let buffer = core::mem::ManuallyDrop::new(self);
+
+        // SAFETY: the allocation was made by the global allocator with an alignment of 1 and the size of the
+        // capacity, which matches the layout of a Vec<u8>.
+        unsafe { Vec::from_raw_parts(buffer.pointer.as_ptr(), buffer.length, buffer.cap) }
+    }
+
     /// Returns the length of the [`ByteBuffer`].
     ///
     /// The length of the buffer is the last index written to - 1.
@@ -707,6 +929,118 @@ impl ByteBuffer {
         self.cursor
     }
 
+    /// Returns the [`LengthPrefix`] used to store the length of strings and collections.
+    ///
+    /// # Examples
+    /// ```
+    /// use bytey_byte_buffer::{byte_buffer::ByteBuffer, length_prefix::LengthPrefix};
+    ///
+    /// let buffer = ByteBuffer::new().unwrap();
+    ///
+    /// assert_eq!(buffer.length_prefix(), LengthPrefix::U64);
+    /// ```
+    pub fn length_prefix(&self) -> LengthPrefix {
+        self.length_prefix
+    }
+
+    /// Sets the [`LengthPrefix`] used to store the length of strings and collections.
+    ///
+    /// # Behaviour
+    /// Only data written or read after the call is affected. Writing a length that does not fit in the prefix
+    /// returns [`ByteBufferError::LengthOverflow`].
+    ///
+    /// # Examples
+    /// ```
+    /// use bytey_byte_buffer::{byte_buffer::ByteBuffer, length_prefix::LengthPrefix};
+    ///
+    /// let mut buffer = ByteBuffer::new().unwrap();
+    ///
+    /// buffer.set_length_prefix(LengthPrefix::U16);
+    /// buffer.write(vec![1u8, 2, 3]).unwrap();
+    ///
+    /// assert_eq!(buffer.length(), 5);
+    /// ```
+    pub fn set_length_prefix(&mut self, length_prefix: LengthPrefix) -> &mut Self {
+        self.length_prefix = length_prefix;
+
+        self
+    }
+
+    /// Returns true if maps and sets are written in canonical order, see [`set_canonical`](Self::set_canonical).
+    pub fn canonical(&self) -> bool {
+        self.canonical
+    }
+
+    /// Sets whether values are encoded canonically, so identical values always produce identical bytes.
+    ///
+    /// # Behaviour
+    /// - Maps, sets and binary heaps are written sorted by the encoded bytes of their keys, instead of in
+    ///   iteration order which depends on the hasher or the layout of the heap.
+    /// - Reading a map or set with a duplicate key returns [`ByteBufferError::DuplicateKey`].
+    ///
+    /// # Examples
+    /// ```
+    /// use bytey_byte_buffer::byte_buffer::ByteBuffer;
+    /// use std::collections::{BTreeSet, HashSet};
+    ///
+    /// let mut hashed = ByteBuffer::new().unwrap();
+    /// let mut ordered = ByteBuffer::new().unwrap();
+    ///
+    /// hashed.set_canonical(true);
+    /// ordered.set_canonical(true);
+    ///
+    /// hashed.write(HashSet::from([-1i8, 1, 2])).unwrap();
+    /// ordered.write(BTreeSet::from([-1i8, 1, 2])).unwrap();
+    ///
+    /// // -1 is encoded as 0xFF, so it is written last.
+    /// assert_eq!(hashed.as_bytes(), ordered.as_bytes());
+    /// assert_eq!(&hashed.as_bytes()[8..], [1, 2, 0xFF]);
+    /// ```
+    pub fn set_canonical(&mut self, canonical: bool) -> &mut Self {
+        self.canonical = canonical;
+
+        self
+    }
+
+    /// Returns the [`DecodeLimits`] enforced while reading values.
+    pub fn decode_limits(&self) -> DecodeLimits {
+        self.decode_limits
+    }
+
+    /// Sets the [`DecodeLimits`] enforced while reading values, use this before reading untrusted data.
+    ///
+    /// # Behaviour
+    /// The limits are also used by the [`reader`](Self::reader) and the buffers returned by
+    /// [`read_to_buffer`](Self::read_to_buffer).
+    ///
+    /// # Examples
+    /// ```
+    /// use bytey_byte_buffer::{byte_buffer::ByteBuffer, decode_limits::DecodeLimits, error::ByteBufferError};
+    ///
+    /// let mut buffer = ByteBuffer::new().unwrap();
+    /// let mut limits = DecodeLimits::new();
+    ///
+    /// limits.set_max_string_length(4);
+    /// buffer.set_decode_limits(limits);
+    ///
+    /// buffer.write("hello").unwrap();
+    /// buffer.move_cursor(0).unwrap();
+    ///
+    /// assert_eq!(
+    ///     buffer.read::<String>(),
+    ///     Err(ByteBufferError::StringTooLong { length: 5, max: 4 })
+    /// );
+    /// ```
+    pub fn set_decode_limits(&mut self, decode_limits: DecodeLimits) -> &mut Self {
+        self.decode_limits = decode_limits.reset();
+
+        self
+    }
+
+    pub(crate) fn limits_mut(&mut self) -> &mut DecodeLimits {
+        &mut self.decode_limits
+    }
+
     /// Returns a const pointer to the allocation.
     ///
     /// # Safety
@@ -736,6 +1070,48 @@ impl ByteBuffer {
         self.length() == 0
     }
 
+    /// Returns a [`ByteBufferRef`] over the data of the [`ByteBuffer`] to read values that borrow from it.
+    ///
+    /// # Behaviour
+    /// The reader starts at the current cursor position and has its own cursor, the cursor of the
+    /// [`ByteBuffer`] is not moved.
+    ///
+    /// # Examples
+    /// ```
+    /// use bytey_byte_buffer::byte_buffer::ByteBuffer;
+    ///
+    /// let mut buffer = ByteBuffer::new().unwrap();
+    ///
+    /// buffer.write(vec![1u8, 2, 3]).unwrap();
+    /// buffer.move_cursor(0).unwrap();
+    ///
+    /// let mut reader = buffer.reader();
+    /// let bytes: &[u8] = reader.read_ref().unwrap();
+    ///
+    /// assert_eq!(bytes, [1, 2, 3]);
+    /// ```
+    pub fn reader(&self) -> ByteBufferRef<'_> {
+        let data = unsafe { slice::from_raw_parts(self.pointer.as_ptr(), self.length) };
+
+        ByteBufferRef::with_cursor(
+            data,
+            self.cursor,
+            self.length_prefix,
+            self.canonical,
+            self.decode_limits.reset(),
+        )
+    }
+
+    /// Returns the bytes between the current cursor position and the length of the buffer, without moving the cursor.
+    pub(crate) fn remaining(&self) -> &[u8] {
+        unsafe {
+            slice::from_raw_parts(
+                self.pointer.as_ptr().add(self.cursor),
+                self.length - self.cursor,
+            )
+        }
+    }
+
     /// Returns a new [`ByteBuffer`] with data at the old [`ByteBuffer`]'s Cursor
     /// to the length of the New Buffer. This will move the old buffers cursor.
     ///
@@ -760,16 +1136,39 @@ impl ByteBuffer {
     #[inline]
     pub fn read_to_buffer(&mut self, len: usize) -> Result<Self> {
         let mut buffer = ByteBuffer::with_capacity(len)?;
+        buffer.length_prefix = self.length_prefix;
+        buffer.canonical = self.canonical;
+        buffer.decode_limits = self.decode_limits.reset();
         let bytes = self.read_slice(len)?;
         buffer.write_slice(bytes)?;
         buffer.cursor = 0;
         Ok(buffer)
     }
 
+    /// Returns a slice of the entire [`ByteBuffer`] up to its length.
+    ///
+    /// Unlike [`as_slice`](Self::as_slice), the cursor position is not changed.
+    ///
+    /// # Examples
+    /// ```
+    /// use bytey_byte_buffer::byte_buffer::ByteBuffer;
+    ///
+    /// let mut buffer = ByteBuffer::new().unwrap();
+    ///
+    /// buffer.write(1u16);
+    ///
+    /// assert_eq!(buffer.as_bytes().len(), 2);
+    /// assert_eq!(buffer.cursor(), 2);
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        unsafe { slice::from_raw_parts(self.pointer.as_ptr(), self.length) }
+    }
+
     /// Returns a slice of the entire [`ByteBuffer`] from cursor position 0.
     ///
     /// # Behaviour
     /// The current cursor position will be self.length.
+    /// Use [`as_bytes`](Self::as_bytes) to get the same slice without moving the cursor.
     ///
     /// # Examples
     /// ```
@@ -809,11 +1208,11 @@ impl ByteBuffer {
     /// assert_eq!(slice.len(), 4);
     /// ```
     pub fn slice_from(&mut self, cursor: usize, size: usize) -> Result<&[u8]> {
-        if cursor + size > self.length {
+        if cursor.checked_add(size).is_none_or(|end| end > self.length) {
             return Err(ByteBufferError::ReadOutOfBounds {
                 length: self.length,
                 start: cursor,
-                end: cursor + size,
+                end: cursor.saturating_add(size),
             });
         }
 
@@ -844,6 +1243,65 @@ impl Clone for ByteBuffer {
             length: self.length,
             cursor: self.cursor,
             pointer: NonNull::new(pointer).unwrap(),
+            length_prefix: self.length_prefix,
+            canonical: self.canonical,
+            decode_limits: self.decode_limits.reset(),
         }
     }
 }
+
+impl From<Vec<u8>> for ByteBuffer {
+    /// Takes over the allocation of the vector without copying, the cursor is set to 0.
+    ///
+    /// A vector without capacity allocates [`MIN_SIZE`](ByteBuffer::MIN_SIZE) bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use bytey_byte_buffer::byte_buffer::ByteBuffer;
+    ///
+    /// let mut buffer = ByteBuffer::from(vec![1, 0]);
+    ///
+    /// assert_eq!(buffer.read_le::<u16>().unwrap(), 1);
+    /// ```
+    fn from(vec: Vec<u8>) -> Self {
+        ByteBuffer::from_vec(vec)
+    }
+}
+
+impl From<ByteBuffer> for Vec<u8> {
+    /// Hands the allocation of the buffer over to a vector of the bytes up to its length, without copying.
+    ///
+    /// # Examples
+    /// ```
+    /// use bytey_byte_buffer::byte_buffer::ByteBuffer;
+    ///
+    /// let mut buffer = ByteBuffer::new().unwrap();
+    ///
+    /// buffer.write_le(1u16);
+    ///
+    /// assert_eq!(Vec::from(buffer), [1, 0]);
+    /// ```
+    fn from(buffer: ByteBuffer) -> Self {
+        buffer.into_vec()
+    }
+}
+
+impl AsRef<[u8]> for ByteBuffer {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl Borrow<[u8]> for ByteBuffer {
+    fn borrow(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl Deref for ByteBuffer {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{byte_buffer::ByteBuffer, error::Result};
+use crate::{error::Result, stream::Source};
 
 mod arrays;
 mod bound;
@@ -6,23 +6,27 @@ mod boxed;
 mod byte;
 mod cell;
 mod char;
+mod collections;
 mod cow;
+mod length;
 mod num;
 mod option;
 mod phantom;
 mod result;
+mod shared;
 mod string;
 mod time;
 mod tuple;
+mod varint;
 
 pub trait ByteBufferRead {
-    fn read_from_bytey_buffer(buffer: &mut ByteBuffer) -> Result<Self>
+    fn read_from_bytey_buffer<S: Source>(buffer: &mut S) -> Result<Self>
     where
         Self: Sized;
-    fn read_from_bytey_buffer_le(buffer: &mut ByteBuffer) -> Result<Self>
+    fn read_from_bytey_buffer_le<S: Source>(buffer: &mut S) -> Result<Self>
     where
         Self: Sized;
-    fn read_from_bytey_buffer_be(buffer: &mut ByteBuffer) -> Result<Self>
+    fn read_from_bytey_buffer_be<S: Source>(buffer: &mut S) -> Result<Self>
     where
         Self: Sized;
 }
@@ -31,16 +35,16 @@ macro_rules! impl_byte_buffer_read_types {
     ($($type:ty),*) => {
         $(
             impl ByteBufferRead for $type {
-                fn read_from_bytey_buffer(buffer: &mut ByteBuffer) -> Result<$type> {
-                    Ok(<$type>::from_ne_bytes(buffer.read_slice(std::mem::size_of::<$type>())?.try_into().expect("This really shouldn't fail")))
+                fn read_from_bytey_buffer<S: Source>(buffer: &mut S) -> Result<$type> {
+                    Ok(<$type>::from_ne_bytes(buffer.read_slice(core::mem::size_of::<$type>())?.try_into().expect("This really shouldn't fail")))
                 }
 
-                fn read_from_bytey_buffer_le(buffer: &mut ByteBuffer) -> Result<$type> {
-                    Ok(<$type>::from_le_bytes(buffer.read_slice(std::mem::size_of::<$type>())?.try_into().expect("This really shouldn't fail")))
+                fn read_from_bytey_buffer_le<S: Source>(buffer: &mut S) -> Result<$type> {
+                    Ok(<$type>::from_le_bytes(buffer.read_slice(core::mem::size_of::<$type>())?.try_into().expect("This really shouldn't fail")))
                 }
 
-                fn read_from_bytey_buffer_be(buffer: &mut ByteBuffer) -> Result<$type> {
-                    Ok(<$type>::from_be_bytes(buffer.read_slice(std::mem::size_of::<$type>())?.try_into().expect("This really shouldn't fail")))
+                fn read_from_bytey_buffer_be<S: Source>(buffer: &mut S) -> Result<$type> {
+                    Ok(<$type>::from_be_bytes(buffer.read_slice(core::mem::size_of::<$type>())?.try_into().expect("This really shouldn't fail")))
                 }
             }
         )*
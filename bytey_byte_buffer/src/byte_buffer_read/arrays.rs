@@ -1,9 +1,12 @@
 use crate::{
-    byte_buffer::ByteBuffer,
     byte_buffer_read::ByteBufferRead,
+    decode_limits::preallocation,
     error::{ByteBufferError, Result},
+    length_prefix::Length,
+    stream::Source,
 };
-use std::{
+use alloc::vec::Vec;
+use core::{
     any::TypeId,
     mem::{self, MaybeUninit},
 };
@@ -21,7 +24,7 @@ impl<T, const N: usize> Drop for Guard<'_, T, N> {
 
         // SAFETY: this slice will contain only initialized objects.
         unsafe {
-            std::ptr::drop_in_place(
+            core::ptr::drop_in_place(
                 &mut *(self.array_mut.get_unchecked_mut(..self.initialized)
                     as *mut [MaybeUninit<T>] as *mut [T]),
             );
@@ -31,18 +34,13 @@ impl<T, const N: usize> Drop for Guard<'_, T, N> {
 
 impl<T: ByteBufferRead + 'static, const N: usize> ByteBufferRead for [T; N] {
     #[inline]
-    fn read_from_bytey_buffer(buffer: &mut ByteBuffer) -> Result<Self> {
-        if N == 0 {
-            return Err(ByteBufferError::OtherError {
-                error: "Can not read to an [T;0]. The array must have a size.".to_owned(),
-            });
-        }
-
-        let size = buffer.read::<u64>()? as usize;
+    fn read_from_bytey_buffer<S: Source>(buffer: &mut S) -> Result<Self> {
+        let size = buffer.read::<Length>()?.0;
 
         if size != N {
-            return Err(ByteBufferError::OtherError {
-                error: format!("Invalid size in buffer for [T; {N}]. Should be [T; {size}]?",),
+            return Err(ByteBufferError::ArrayLengthMismatch {
+                expected: N,
+                found: size,
             });
         }
 
@@ -59,8 +57,10 @@ impl<T: ByteBufferRead + 'static, const N: usize> ByteBufferRead for [T; N] {
                 initialized: 0,
             };
 
-            for _ in 0..N {
-                let item = buffer.read::<T>()?;
+            for index in 0..N {
+                let item = buffer
+                    .read::<T>()
+                    .map_err(|error| error.with_index(index, buffer.offset()))?;
 
                 // SAFETY: `guard.initialized` starts at 0, is increased by one in the
                 // loop and the loop is aborted once it reaches N (which is
@@ -74,34 +74,23 @@ impl<T: ByteBufferRead + 'static, const N: usize> ByteBufferRead for [T; N] {
                 guard.initialized += 1;
             }
 
-            if guard.initialized == N {
-                mem::forget(guard);
-
-                // SAFETY: the condition above asserts that all elements are
-                // initialized.
-                let out = unsafe { (&array as *const _ as *const [T; N]).read() };
-                Ok(out)
-            } else {
-                Err(ByteBufferError::OtherError {
-                    error: "Not all of the array was initialized".to_owned(),
-                })
-            }
+            debug_assert_eq!(guard.initialized, N);
+            mem::forget(guard);
+
+            // SAFETY: the loop above returns early on an error, so all elements are initialized.
+            let out = unsafe { (&array as *const _ as *const [T; N]).read() };
+            Ok(out)
         }
     }
 
     #[inline]
-    fn read_from_bytey_buffer_le(buffer: &mut ByteBuffer) -> Result<Self> {
-        if N == 0 {
-            return Err(ByteBufferError::OtherError {
-                error: "Can not read to an [T;0]. The array must have a size.".to_owned(),
-            });
-        }
-
-        let size = buffer.read_le::<u64>()? as usize;
+    fn read_from_bytey_buffer_le<S: Source>(buffer: &mut S) -> Result<Self> {
+        let size = buffer.read_le::<Length>()?.0;
 
         if size != N {
-            return Err(ByteBufferError::OtherError {
-                error: format!("Invalid size in buffer for [T; {N}]. Should be [T; {size}]?",),
+            return Err(ByteBufferError::ArrayLengthMismatch {
+                expected: N,
+                found: size,
             });
         }
 
@@ -118,8 +107,10 @@ impl<T: ByteBufferRead + 'static, const N: usize> ByteBufferRead for [T; N] {
                 initialized: 0,
             };
 
-            for _ in 0..N {
-                let item = buffer.read_le::<T>()?;
+            for index in 0..N {
+                let item = buffer
+                    .read_le::<T>()
+                    .map_err(|error| error.with_index(index, buffer.offset()))?;
 
                 // SAFETY: `guard.initialized` starts at 0, is increased by one in the
                 // loop and the loop is aborted once it reaches N (which is
@@ -133,34 +124,23 @@ impl<T: ByteBufferRead + 'static, const N: usize> ByteBufferRead for [T; N] {
                 guard.initialized += 1;
             }
 
-            if guard.initialized == N {
-                mem::forget(guard);
-
-                // SAFETY: the condition above asserts that all elements are
-                // initialized.
-                let out = unsafe { (&array as *const _ as *const [T; N]).read() };
-                Ok(out)
-            } else {
-                Err(ByteBufferError::OtherError {
-                    error: "Not all of the array was initialized".to_owned(),
-                })
-            }
+            debug_assert_eq!(guard.initialized, N);
+            mem::forget(guard);
+
+            // SAFETY: the loop above returns early on an error, so all elements are initialized.
+            let out = unsafe { (&array as *const _ as *const [T; N]).read() };
+            Ok(out)
         }
     }
 
     #[inline]
-    fn read_from_bytey_buffer_be(buffer: &mut ByteBuffer) -> Result<Self> {
-        if N == 0 {
-            return Err(ByteBufferError::OtherError {
-                error: "Can not read to an [T;0]. The array must have a size.".to_owned(),
-            });
-        }
-
-        let size = buffer.read_be::<u64>()? as usize;
+    fn read_from_bytey_buffer_be<S: Source>(buffer: &mut S) -> Result<Self> {
+        let size = buffer.read_be::<Length>()?.0;
 
         if size != N {
-            return Err(ByteBufferError::OtherError {
-                error: format!("Invalid size in buffer for [T; {N}]. Should be [T; {size}]?",),
+            return Err(ByteBufferError::ArrayLengthMismatch {
+                expected: N,
+                found: size,
             });
         }
 
@@ -177,8 +157,10 @@ impl<T: ByteBufferRead + 'static, const N: usize> ByteBufferRead for [T; N] {
                 initialized: 0,
             };
 
-            for _ in 0..N {
-                let item = buffer.read_be::<T>()?;
+            for index in 0..N {
+                let item = buffer
+                    .read_be::<T>()
+                    .map_err(|error| error.with_index(index, buffer.offset()))?;
 
                 // SAFETY: `guard.initialized` starts at 0, is increased by one in the
                 // loop and the loop is aborted once it reaches N (which is
@@ -192,71 +174,89 @@ impl<T: ByteBufferRead + 'static, const N: usize> ByteBufferRead for [T; N] {
                 guard.initialized += 1;
             }
 
-            if guard.initialized == N {
-                mem::forget(guard);
-
-                // SAFETY: the condition above asserts that all elements are
-                // initialized.
-                let out = unsafe { (&array as *const _ as *const [T; N]).read() };
-                Ok(out)
-            } else {
-                Err(ByteBufferError::OtherError {
-                    error: "Not all of the array was initialized".to_owned(),
-                })
-            }
+            debug_assert_eq!(guard.initialized, N);
+            mem::forget(guard);
+
+            // SAFETY: the loop above returns early on an error, so all elements are initialized.
+            let out = unsafe { (&array as *const _ as *const [T; N]).read() };
+            Ok(out)
         }
     }
 }
 
 impl<T: ByteBufferRead> ByteBufferRead for Vec<T> {
     #[inline]
-    fn read_from_bytey_buffer(buffer: &mut ByteBuffer) -> Result<Self> {
-        let size = buffer.read::<u64>()? as usize;
+    fn read_from_bytey_buffer<S: Source>(buffer: &mut S) -> Result<Self> {
+        let size = buffer.read::<Length>()?.0;
 
         if size == 0 {
             return Ok(Vec::new());
         }
 
-        let mut vec = Vec::with_capacity(size);
+        buffer.read_nested(|buffer| {
+            buffer.limit_collection::<T>(size)?;
 
-        for _ in 0..size {
-            vec.push(buffer.read::<T>()?);
-        }
+            let mut vec = Vec::with_capacity(preallocation::<T>(size));
+
+            for index in 0..size {
+                vec.push(
+                    buffer
+                        .read::<T>()
+                        .map_err(|error| error.with_index(index, buffer.offset()))?,
+                );
+            }
 
-        Ok(vec)
+            Ok(vec)
+        })
     }
 
     #[inline]
-    fn read_from_bytey_buffer_le(buffer: &mut ByteBuffer) -> Result<Self> {
-        let size = buffer.read_le::<u64>()? as usize;
+    fn read_from_bytey_buffer_le<S: Source>(buffer: &mut S) -> Result<Self> {
+        let size = buffer.read_le::<Length>()?.0;
 
         if size == 0 {
             return Ok(Vec::new());
         }
 
-        let mut vec = Vec::with_capacity(size);
+        buffer.read_nested(|buffer| {
+            buffer.limit_collection::<T>(size)?;
 
-        for _ in 0..size {
-            vec.push(buffer.read_le::<T>()?);
-        }
+            let mut vec = Vec::with_capacity(preallocation::<T>(size));
+
+            for index in 0..size {
+                vec.push(
+                    buffer
+                        .read_le::<T>()
+                        .map_err(|error| error.with_index(index, buffer.offset()))?,
+                );
+            }
 
-        Ok(vec)
+            Ok(vec)
+        })
     }
 
     #[inline]
-    fn read_from_bytey_buffer_be(buffer: &mut ByteBuffer) -> Result<Self> {
-        let size = buffer.read_be::<u64>()? as usize;
+    fn read_from_bytey_buffer_be<S: Source>(buffer: &mut S) -> Result<Self> {
+        let size = buffer.read_be::<Length>()?.0;
 
         if size == 0 {
             return Ok(Vec::new());
         }
 
-        let mut vec = Vec::with_capacity(size);
+        buffer.read_nested(|buffer| {
+            buffer.limit_collection::<T>(size)?;
 
-        for _ in 0..size {
-            vec.push(buffer.read_be::<T>()?);
-        }
+            let mut vec = Vec::with_capacity(preallocation::<T>(size));
+
+            for index in 0..size {
+                vec.push(
+                    buffer
+                        .read_be::<T>()
+                        .map_err(|error| error.with_index(index, buffer.offset()))?,
+                );
+            }
 
-        Ok(vec)
+            Ok(vec)
+        })
     }
 }
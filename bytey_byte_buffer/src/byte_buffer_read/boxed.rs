@@ -1,18 +1,65 @@
-use crate::{byte_buffer::ByteBuffer, byte_buffer_read::ByteBufferRead, error::Result};
+use crate::{byte_buffer_read::ByteBufferRead, error::Result, stream::Source};
+use alloc::{boxed::Box, string::String, vec::Vec};
 
 impl<T: ByteBufferRead> ByteBufferRead for Box<T> {
     #[inline]
-    fn read_from_bytey_buffer(buffer: &mut ByteBuffer) -> Result<Box<T>> {
-        Ok(Box::new(buffer.read::<T>()?))
+    fn read_from_bytey_buffer<S: Source>(buffer: &mut S) -> Result<Box<T>> {
+        buffer.read_nested(|buffer| {
+            buffer.limit_allocation(core::mem::size_of::<T>())?;
+
+            Ok(Box::new(buffer.read::<T>()?))
+        })
+    }
+
+    #[inline]
+    fn read_from_bytey_buffer_le<S: Source>(buffer: &mut S) -> Result<Box<T>> {
+        buffer.read_nested(|buffer| {
+            buffer.limit_allocation(core::mem::size_of::<T>())?;
+
+            Ok(Box::new(buffer.read_le::<T>()?))
+        })
+    }
+
+    #[inline]
+    fn read_from_bytey_buffer_be<S: Source>(buffer: &mut S) -> Result<Box<T>> {
+        buffer.read_nested(|buffer| {
+            buffer.limit_allocation(core::mem::size_of::<T>())?;
+
+            Ok(Box::new(buffer.read_be::<T>()?))
+        })
+    }
+}
+
+impl ByteBufferRead for Box<str> {
+    #[inline]
+    fn read_from_bytey_buffer<S: Source>(buffer: &mut S) -> Result<Box<str>> {
+        Ok(buffer.read::<String>()?.into_boxed_str())
+    }
+
+    #[inline]
+    fn read_from_bytey_buffer_le<S: Source>(buffer: &mut S) -> Result<Box<str>> {
+        Ok(buffer.read_le::<String>()?.into_boxed_str())
+    }
+
+    #[inline]
+    fn read_from_bytey_buffer_be<S: Source>(buffer: &mut S) -> Result<Box<str>> {
+        Ok(buffer.read_be::<String>()?.into_boxed_str())
+    }
+}
+
+impl<T: ByteBufferRead> ByteBufferRead for Box<[T]> {
+    #[inline]
+    fn read_from_bytey_buffer<S: Source>(buffer: &mut S) -> Result<Box<[T]>> {
+        Ok(buffer.read::<Vec<T>>()?.into_boxed_slice())
     }
 
     #[inline]
-    fn read_from_bytey_buffer_le(buffer: &mut ByteBuffer) -> Result<Box<T>> {
-        Ok(Box::new(buffer.read_le::<T>()?))
+    fn read_from_bytey_buffer_le<S: Source>(buffer: &mut S) -> Result<Box<[T]>> {
+        Ok(buffer.read_le::<Vec<T>>()?.into_boxed_slice())
     }
 
     #[inline]
-    fn read_from_bytey_buffer_be(buffer: &mut ByteBuffer) -> Result<Box<T>> {
-        Ok(Box::new(buffer.read_be::<T>()?))
+    fn read_from_bytey_buffer_be<S: Source>(buffer: &mut S) -> Result<Box<[T]>> {
+        Ok(buffer.read_be::<Vec<T>>()?.into_boxed_slice())
     }
 }
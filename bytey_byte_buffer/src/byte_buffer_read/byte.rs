@@ -1,18 +1,18 @@
-use crate::{byte_buffer::ByteBuffer, byte_buffer_read::ByteBufferRead, error::Result};
+use crate::{byte_buffer_read::ByteBufferRead, error::Result, stream::Source};
 
 impl ByteBufferRead for bool {
     #[inline]
-    fn read_from_bytey_buffer(buffer: &mut ByteBuffer) -> Result<bool> {
+    fn read_from_bytey_buffer<S: Source>(buffer: &mut S) -> Result<bool> {
         Ok(buffer.read::<u8>()? != 0)
     }
 
     #[inline]
-    fn read_from_bytey_buffer_le(buffer: &mut ByteBuffer) -> Result<bool> {
+    fn read_from_bytey_buffer_le<S: Source>(buffer: &mut S) -> Result<bool> {
         Ok(buffer.read::<u8>()? != 0)
     }
 
     #[inline]
-    fn read_from_bytey_buffer_be(buffer: &mut ByteBuffer) -> Result<bool> {
+    fn read_from_bytey_buffer_be<S: Source>(buffer: &mut S) -> Result<bool> {
         Ok(buffer.read::<u8>()? != 0)
     }
 }
@@ -0,0 +1,142 @@
+use crate::{
+    byte_buffer_read::ByteBufferRead,
+    decode_limits::preallocation,
+    error::{ByteBufferError, Result},
+    length_prefix::Length,
+    stream::Source,
+};
+use alloc::collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque};
+#[cfg(feature = "std")]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+
+/// Reads `length` elements with `read` and inserts them into the collection made by `new`.
+///
+/// `new` gets the capacity to reserve and `insert` returns false if the collection already held the element's key,
+/// which is an error if the source is [`canonical`](Source::canonical). The collection is checked against the
+/// [`DecodeLimits`](crate::decode_limits::DecodeLimits) of the source and read one level deeper, errors get the
+/// index of the element added to their path.
+#[inline]
+fn read_elements<S: Source, T, C>(
+    buffer: &mut S,
+    length: usize,
+    read: fn(&mut S) -> Result<T>,
+    new: impl FnOnce(usize) -> C,
+    mut insert: impl FnMut(&mut C, T) -> bool,
+) -> Result<C> {
+    buffer.read_nested(|buffer| {
+        buffer.limit_collection::<T>(length)?;
+
+        let canonical = buffer.canonical();
+        let mut collection = new(preallocation::<T>(length));
+
+        for index in 0..length {
+            let element = read(buffer).map_err(|error| error.with_index(index, buffer.offset()))?;
+
+            if !insert(&mut collection, element) && canonical {
+                return Err(ByteBufferError::DuplicateKey.with_index(index, buffer.offset()));
+            }
+        }
+
+        Ok(collection)
+    })
+}
+
+/// The key and value of a map, written like a tuple of the two.
+///
+/// Errors get `key` or `value` added to their path, so a bad value of the fourth entry shows up as `[3].value`.
+struct MapEntry<K, V>(K, V);
+
+/// Reads a [`MapEntry`] with the given read functions of the key and value.
+#[inline]
+fn read_entry<S: Source, K, V>(
+    buffer: &mut S,
+    read_key: fn(&mut S) -> Result<K>,
+    read_value: fn(&mut S) -> Result<V>,
+) -> Result<MapEntry<K, V>> {
+    let key =
+        read_key(buffer).map_err(|error| error.with_field("map entry", "key", buffer.offset()))?;
+    let value = read_value(buffer)
+        .map_err(|error| error.with_field("map entry", "value", buffer.offset()))?;
+
+    Ok(MapEntry(key, value))
+}
+
+impl<K: ByteBufferRead, V: ByteBufferRead> ByteBufferRead for MapEntry<K, V> {
+    #[inline]
+    fn read_from_bytey_buffer<S: Source>(buffer: &mut S) -> Result<Self> {
+        read_entry(buffer, S::read::<K>, S::read::<V>)
+    }
+
+    #[inline]
+    fn read_from_bytey_buffer_le<S: Source>(buffer: &mut S) -> Result<Self> {
+        read_entry(buffer, S::read_le::<K>, S::read_le::<V>)
+    }
+
+    #[inline]
+    fn read_from_bytey_buffer_be<S: Source>(buffer: &mut S) -> Result<Self> {
+        read_entry(buffer, S::read_be::<K>, S::read_be::<V>)
+    }
+}
+
+/// Implements [`ByteBufferRead`] for a collection written as the [`Length`] followed by its elements.
+///
+/// `$element` is the type read for every element, map entries are read as a [`MapEntry`].
+/// `$new` makes the collection from the capacity to reserve and `$insert` adds an element to it, returning false
+/// if its key was already in the collection.
+macro_rules! impl_read_collection {
+    ($([$($generics:tt)*] $type:ty => $element:ty, $new:expr, $insert:expr;)*) => {
+        $(
+            impl<$($generics)*> ByteBufferRead for $type {
+                #[inline]
+                fn read_from_bytey_buffer<S: Source>(buffer: &mut S) -> Result<Self> {
+                    let length = buffer.read::<Length>()?.0;
+
+                    read_elements(buffer, length, S::read::<$element>, $new, $insert)
+                }
+
+                #[inline]
+                fn read_from_bytey_buffer_le<S: Source>(buffer: &mut S) -> Result<Self> {
+                    let length = buffer.read_le::<Length>()?.0;
+
+                    read_elements(buffer, length, S::read_le::<$element>, $new, $insert)
+                }
+
+                #[inline]
+                fn read_from_bytey_buffer_be<S: Source>(buffer: &mut S) -> Result<Self> {
+                    let length = buffer.read_be::<Length>()?.0;
+
+                    read_elements(buffer, length, S::read_be::<$element>, $new, $insert)
+                }
+            }
+        )*
+    };
+}
+
+impl_read_collection! {
+    [T: ByteBufferRead] VecDeque<T> => T, VecDeque::with_capacity, |deque: &mut VecDeque<T>, e| {
+        deque.push_back(e);
+        true
+    };
+    [T: ByteBufferRead] LinkedList<T> => T, |_| LinkedList::new(), |list: &mut LinkedList<T>, e| {
+        list.push_back(e);
+        true
+    };
+    [T: ByteBufferRead + Ord] BinaryHeap<T> => T, BinaryHeap::with_capacity, |heap: &mut BinaryHeap<T>, e| {
+        heap.push(e);
+        true
+    };
+    [T: ByteBufferRead + Ord] BTreeSet<T> => T, |_| BTreeSet::new(), BTreeSet::insert;
+    [K: ByteBufferRead + Ord, V: ByteBufferRead] BTreeMap<K, V> => MapEntry<K, V>, |_| BTreeMap::new(),
+        |map: &mut BTreeMap<K, V>, MapEntry(k, v)| map.insert(k, v).is_none();
+}
+
+#[cfg(feature = "std")]
+impl_read_collection! {
+    [T: ByteBufferRead + Eq + Hash, H: BuildHasher + Default] HashSet<T, H> => T,
+        |capacity| HashSet::with_capacity_and_hasher(capacity, H::default()), HashSet::insert;
+    [K: ByteBufferRead + Eq + Hash, V: ByteBufferRead, H: BuildHasher + Default] HashMap<K, V, H> => MapEntry<K, V>,
+        |capacity| HashMap::with_capacity_and_hasher(capacity, H::default()),
+        |map: &mut HashMap<K, V, H>, MapEntry(k, v)| map.insert(k, v).is_none();
+}
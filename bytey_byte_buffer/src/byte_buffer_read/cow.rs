@@ -1,19 +1,19 @@
-use crate::{byte_buffer::ByteBuffer, byte_buffer_read::ByteBufferRead, error::Result};
-use std::borrow::Cow;
+use crate::{byte_buffer_read::ByteBufferRead, error::Result, stream::Source};
+use alloc::borrow::{Cow, ToOwned};
 
 impl<T: ByteBufferRead + ToOwned<Owned = T>> ByteBufferRead for Cow<'_, T> {
     #[inline]
-    fn read_from_bytey_buffer(buffer: &mut ByteBuffer) -> Result<Self> {
+    fn read_from_bytey_buffer<S: Source>(buffer: &mut S) -> Result<Self> {
         Ok(Cow::Owned(buffer.read::<T>()?))
     }
 
     #[inline]
-    fn read_from_bytey_buffer_le(buffer: &mut ByteBuffer) -> Result<Self> {
+    fn read_from_bytey_buffer_le<S: Source>(buffer: &mut S) -> Result<Self> {
         Ok(Cow::Owned(buffer.read_le::<T>()?))
     }
 
     #[inline]
-    fn read_from_bytey_buffer_be(buffer: &mut ByteBuffer) -> Result<Self> {
+    fn read_from_bytey_buffer_be<S: Source>(buffer: &mut S) -> Result<Self> {
         Ok(Cow::Owned(buffer.read_be::<T>()?))
     }
 }
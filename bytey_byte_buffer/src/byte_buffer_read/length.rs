@@ -0,0 +1,48 @@
+use crate::{
+    byte_buffer_read::ByteBufferRead,
+    error::Result,
+    length_prefix::{Length, LengthPrefix},
+    stream::Source,
+    varint::VarU64,
+};
+
+impl ByteBufferRead for Length {
+    #[inline]
+    fn read_from_bytey_buffer<S: Source>(buffer: &mut S) -> Result<Self> {
+        let length = match buffer.length_prefix() {
+            LengthPrefix::U8 => buffer.read::<u8>()?.into(),
+            LengthPrefix::U16 => buffer.read::<u16>()?.into(),
+            LengthPrefix::U32 => buffer.read::<u32>()?.into(),
+            LengthPrefix::U64 => buffer.read::<u64>()?,
+            LengthPrefix::VarInt => buffer.read::<VarU64>()?.0,
+        };
+
+        Length::from_u64(length)
+    }
+
+    #[inline]
+    fn read_from_bytey_buffer_le<S: Source>(buffer: &mut S) -> Result<Self> {
+        let length = match buffer.length_prefix() {
+            LengthPrefix::U8 => buffer.read_le::<u8>()?.into(),
+            LengthPrefix::U16 => buffer.read_le::<u16>()?.into(),
+            LengthPrefix::U32 => buffer.read_le::<u32>()?.into(),
+            LengthPrefix::U64 => buffer.read_le::<u64>()?,
+            LengthPrefix::VarInt => buffer.read::<VarU64>()?.0,
+        };
+
+        Length::from_u64(length)
+    }
+
+    #[inline]
+    fn read_from_bytey_buffer_be<S: Source>(buffer: &mut S) -> Result<Self> {
+        let length = match buffer.length_prefix() {
+            LengthPrefix::U8 => buffer.read_be::<u8>()?.into(),
+            LengthPrefix::U16 => buffer.read_be::<u16>()?.into(),
+            LengthPrefix::U32 => buffer.read_be::<u32>()?.into(),
+            LengthPrefix::U64 => buffer.read_be::<u64>()?,
+            LengthPrefix::VarInt => buffer.read::<VarU64>()?.0,
+        };
+
+        Length::from_u64(length)
+    }
+}
@@ -1,19 +1,20 @@
 use crate::{
-    byte_buffer::ByteBuffer,
     byte_buffer_read::ByteBufferRead,
     error::{ByteBufferError, Result},
+    stream::Source,
 };
 
 impl<T: ByteBufferRead> ByteBufferRead for Option<T> {
     #[inline]
-    fn read_from_bytey_buffer(buffer: &mut ByteBuffer) -> Result<Option<T>> {
+    fn read_from_bytey_buffer<S: Source>(buffer: &mut S) -> Result<Option<T>> {
         let data = match buffer.read::<u8>()? {
             1 => Some(buffer.read::<T>()?),
             2 => None,
-            _ => {
-                return Err(ByteBufferError::OtherError {
-                    error: "Invalid Read to Option".to_owned(),
-                })
+            tag => {
+                return Err(ByteBufferError::InvalidTag {
+                    type_name: "Option",
+                    tag: tag.into(),
+                });
             }
         };
 
@@ -21,14 +22,15 @@ impl<T: ByteBufferRead> ByteBufferRead for Option<T> {
     }
 
     #[inline]
-    fn read_from_bytey_buffer_le(buffer: &mut ByteBuffer) -> Result<Option<T>> {
+    fn read_from_bytey_buffer_le<S: Source>(buffer: &mut S) -> Result<Option<T>> {
         let data = match buffer.read_le::<u8>()? {
             1 => Some(buffer.read_le::<T>()?),
             2 => None,
-            _ => {
-                return Err(ByteBufferError::OtherError {
-                    error: "Invalid Read to Option".to_owned(),
-                })
+            tag => {
+                return Err(ByteBufferError::InvalidTag {
+                    type_name: "Option",
+                    tag: tag.into(),
+                });
             }
         };
 
@@ -36,14 +38,15 @@ impl<T: ByteBufferRead> ByteBufferRead for Option<T> {
     }
 
     #[inline]
-    fn read_from_bytey_buffer_be(buffer: &mut ByteBuffer) -> Result<Option<T>> {
+    fn read_from_bytey_buffer_be<S: Source>(buffer: &mut S) -> Result<Option<T>> {
         let data = match buffer.read_be::<u8>()? {
             1 => Some(buffer.read_be::<T>()?),
             2 => None,
-            _ => {
-                return Err(ByteBufferError::OtherError {
-                    error: "Invalid Read to Option".to_owned(),
-                })
+            tag => {
+                return Err(ByteBufferError::InvalidTag {
+                    type_name: "Option",
+                    tag: tag.into(),
+                });
             }
         };
 
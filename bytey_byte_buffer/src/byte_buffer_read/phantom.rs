@@ -1,19 +1,19 @@
-use crate::{byte_buffer::ByteBuffer, byte_buffer_read::ByteBufferRead, error::Result};
-use std::marker::PhantomData;
+use crate::{byte_buffer_read::ByteBufferRead, error::Result, stream::Source};
+use core::marker::PhantomData;
 
 impl<T> ByteBufferRead for PhantomData<T> {
     #[inline]
-    fn read_from_bytey_buffer(_buffer: &mut ByteBuffer) -> Result<Self> {
+    fn read_from_bytey_buffer<S: Source>(_buffer: &mut S) -> Result<Self> {
         Ok(core::marker::PhantomData)
     }
 
     #[inline]
-    fn read_from_bytey_buffer_le(_buffer: &mut ByteBuffer) -> Result<Self> {
+    fn read_from_bytey_buffer_le<S: Source>(_buffer: &mut S) -> Result<Self> {
         Ok(core::marker::PhantomData)
     }
 
     #[inline]
-    fn read_from_bytey_buffer_be(_buffer: &mut ByteBuffer) -> Result<Self> {
+    fn read_from_bytey_buffer_be<S: Source>(_buffer: &mut S) -> Result<Self> {
         Ok(core::marker::PhantomData)
     }
 }
@@ -0,0 +1,77 @@
+use crate::{byte_buffer_read::ByteBufferRead, error::Result, stream::Source};
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+use alloc::{rc::Rc, string::String, vec::Vec};
+
+/// Implements [`ByteBufferRead`] for a shared pointer to a value, `str` or slice, read like the value it points
+/// to. Strings and slices are read as a [`String`] or [`Vec`] first and copied into the pointer.
+macro_rules! impl_read_shared {
+    ($pointer:ident) => {
+        impl<T: ByteBufferRead> ByteBufferRead for $pointer<T> {
+            #[inline]
+            fn read_from_bytey_buffer<S: Source>(buffer: &mut S) -> Result<$pointer<T>> {
+                buffer.read_nested(|buffer| {
+                    buffer.limit_allocation(core::mem::size_of::<T>())?;
+
+                    Ok($pointer::new(buffer.read::<T>()?))
+                })
+            }
+
+            #[inline]
+            fn read_from_bytey_buffer_le<S: Source>(buffer: &mut S) -> Result<$pointer<T>> {
+                buffer.read_nested(|buffer| {
+                    buffer.limit_allocation(core::mem::size_of::<T>())?;
+
+                    Ok($pointer::new(buffer.read_le::<T>()?))
+                })
+            }
+
+            #[inline]
+            fn read_from_bytey_buffer_be<S: Source>(buffer: &mut S) -> Result<$pointer<T>> {
+                buffer.read_nested(|buffer| {
+                    buffer.limit_allocation(core::mem::size_of::<T>())?;
+
+                    Ok($pointer::new(buffer.read_be::<T>()?))
+                })
+            }
+        }
+
+        impl ByteBufferRead for $pointer<str> {
+            #[inline]
+            fn read_from_bytey_buffer<S: Source>(buffer: &mut S) -> Result<$pointer<str>> {
+                Ok($pointer::from(buffer.read::<String>()?))
+            }
+
+            #[inline]
+            fn read_from_bytey_buffer_le<S: Source>(buffer: &mut S) -> Result<$pointer<str>> {
+                Ok($pointer::from(buffer.read_le::<String>()?))
+            }
+
+            #[inline]
+            fn read_from_bytey_buffer_be<S: Source>(buffer: &mut S) -> Result<$pointer<str>> {
+                Ok($pointer::from(buffer.read_be::<String>()?))
+            }
+        }
+
+        impl<T: ByteBufferRead> ByteBufferRead for $pointer<[T]> {
+            #[inline]
+            fn read_from_bytey_buffer<S: Source>(buffer: &mut S) -> Result<$pointer<[T]>> {
+                Ok($pointer::from(buffer.read::<Vec<T>>()?))
+            }
+
+            #[inline]
+            fn read_from_bytey_buffer_le<S: Source>(buffer: &mut S) -> Result<$pointer<[T]>> {
+                Ok($pointer::from(buffer.read_le::<Vec<T>>()?))
+            }
+
+            #[inline]
+            fn read_from_bytey_buffer_be<S: Source>(buffer: &mut S) -> Result<$pointer<[T]>> {
+                Ok($pointer::from(buffer.read_be::<Vec<T>>()?))
+            }
+        }
+    };
+}
+
+impl_read_shared!(Rc);
+#[cfg(target_has_atomic = "ptr")]
+impl_read_shared!(Arc);
@@ -1,36 +1,48 @@
-use crate::{byte_buffer::ByteBuffer, byte_buffer_read::ByteBufferRead, error::Result};
+use crate::{
+    byte_buffer_read::ByteBufferRead,
+    error::Result,
+    length_prefix::Length,
+    stream::Source,
+};
+use alloc::{borrow::ToOwned, string::String};
 
 impl ByteBufferRead for String {
     #[inline]
-    fn read_from_bytey_buffer(buffer: &mut ByteBuffer) -> Result<Self> {
-        let len = buffer.read::<usize>()?;
+    fn read_from_bytey_buffer<S: Source>(buffer: &mut S) -> Result<Self> {
+        let len = buffer.read::<Length>()?.0;
 
         if len == 0 {
             Ok(String::new())
         } else {
-            Ok(std::str::from_utf8(buffer.read_slice(len)?)?.to_owned())
+            buffer.limit_string(len)?;
+
+            Ok(core::str::from_utf8(buffer.read_slice(len)?)?.to_owned())
         }
     }
 
     #[inline]
-    fn read_from_bytey_buffer_le(buffer: &mut ByteBuffer) -> Result<Self> {
-        let len = buffer.read_le::<usize>()?;
+    fn read_from_bytey_buffer_le<S: Source>(buffer: &mut S) -> Result<Self> {
+        let len = buffer.read_le::<Length>()?.0;
 
         if len == 0 {
             Ok(String::new())
         } else {
-            Ok(std::str::from_utf8(buffer.read_slice(len)?)?.to_owned())
+            buffer.limit_string(len)?;
+
+            Ok(core::str::from_utf8(buffer.read_slice(len)?)?.to_owned())
         }
     }
 
     #[inline]
-    fn read_from_bytey_buffer_be(buffer: &mut ByteBuffer) -> Result<Self> {
-        let len = buffer.read_be::<usize>()?;
+    fn read_from_bytey_buffer_be<S: Source>(buffer: &mut S) -> Result<Self> {
+        let len = buffer.read_be::<Length>()?.0;
 
         if len == 0 {
             Ok(String::new())
         } else {
-            Ok(std::str::from_utf8(buffer.read_slice(len)?)?.to_owned())
+            buffer.limit_string(len)?;
+
+            Ok(core::str::from_utf8(buffer.read_slice(len)?)?.to_owned())
         }
     }
 }
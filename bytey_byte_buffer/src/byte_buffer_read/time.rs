@@ -1,13 +1,14 @@
 use crate::{
-    byte_buffer::ByteBuffer,
     byte_buffer_read::ByteBufferRead,
     error::{ByteBufferError, Result},
+    stream::Source,
 };
-use std::time::Duration;
+use alloc::format;
+use core::time::Duration;
 
 impl ByteBufferRead for Duration {
     #[inline]
-    fn read_from_bytey_buffer(buffer: &mut ByteBuffer) -> Result<Self> {
+    fn read_from_bytey_buffer<S: Source>(buffer: &mut S) -> Result<Self> {
         let secs = buffer.read::<u64>()?;
         let nanos = buffer.read::<u32>()?;
 
@@ -15,7 +16,7 @@ impl ByteBufferRead for Duration {
     }
 
     #[inline]
-    fn read_from_bytey_buffer_le(buffer: &mut ByteBuffer) -> Result<Self> {
+    fn read_from_bytey_buffer_le<S: Source>(buffer: &mut S) -> Result<Self> {
         let secs = buffer.read_le::<u64>()?;
         let nanos = buffer.read_le::<u32>()?;
 
@@ -23,7 +24,7 @@ impl ByteBufferRead for Duration {
     }
 
     #[inline]
-    fn read_from_bytey_buffer_be(buffer: &mut ByteBuffer) -> Result<Self> {
+    fn read_from_bytey_buffer_be<S: Source>(buffer: &mut S) -> Result<Self> {
         let secs = buffer.read_be::<u64>()?;
         let nanos = buffer.read_be::<u32>()?;
 
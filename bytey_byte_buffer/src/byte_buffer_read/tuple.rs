@@ -1,8 +1,4 @@
-use crate::{
-    byte_buffer::ByteBuffer,
-    byte_buffer_read::ByteBufferRead,
-    error::{ByteBufferError, Result},
-};
+use crate::{byte_buffer_read::ByteBufferRead, error::Result, stream::Source};
 
 macro_rules! tuple_impls {
     ($(
@@ -14,32 +10,26 @@ macro_rules! tuple_impls {
             impl<$($T: ByteBufferRead),+> ByteBufferRead for ($($T,)+)
             {
                 #[inline]
-                fn read_from_bytey_buffer(buffer: &mut ByteBuffer) -> Result<Self> {
+                fn read_from_bytey_buffer<S: Source>(buffer: &mut S) -> Result<Self> {
                     Ok(($(match buffer.read::<$T>() {
                         Ok(v) => v,
-                        Err(e) => return Err(ByteBufferError::OtherError {
-                            error: format!("{} occured at tuple read location {}", e, $idx),
-                        })
+                        Err(e) => return Err(e.with_field("tuple", stringify!($idx), buffer.offset())),
                     },)+))
                 }
 
                 #[inline]
-                fn read_from_bytey_buffer_le(buffer: &mut ByteBuffer) -> Result<Self> {
+                fn read_from_bytey_buffer_le<S: Source>(buffer: &mut S) -> Result<Self> {
                     Ok(($(match buffer.read_le::<$T>() {
                         Ok(v) => v,
-                        Err(e) => return Err(ByteBufferError::OtherError {
-                            error: format!("{} occured at tuple read location {}", e, $idx),
-                        })
+                        Err(e) => return Err(e.with_field("tuple", stringify!($idx), buffer.offset())),
                     },)+))
                 }
 
                 #[inline]
-                fn read_from_bytey_buffer_be(buffer: &mut ByteBuffer) -> Result<Self> {
+                fn read_from_bytey_buffer_be<S: Source>(buffer: &mut S) -> Result<Self> {
                     Ok(($(match buffer.read_be::<$T>() {
                         Ok(v) => v,
-                        Err(e) => return Err(ByteBufferError::OtherError {
-                            error: format!("{} occured at tuple read location {}", e, $idx),
-                        })
+                        Err(e) => return Err(e.with_field("tuple", stringify!($idx), buffer.offset())),
                     },)+))
                 }
             }
@@ -0,0 +1,80 @@
+use crate::{
+    byte_buffer_read::ByteBufferRead,
+    error::Result,
+    stream::Source,
+    varint::{self, VarI32, VarI64, VarU32, VarU64},
+};
+
+impl ByteBufferRead for VarU32 {
+    #[inline]
+    fn read_from_bytey_buffer<S: Source>(buffer: &mut S) -> Result<VarU32> {
+        Ok(VarU32(
+            varint::read_unsigned(|| buffer.read::<u8>(), u32::BITS, "VarU32")? as u32
+        ))
+    }
+
+    #[inline]
+    fn read_from_bytey_buffer_le<S: Source>(buffer: &mut S) -> Result<VarU32> {
+        Self::read_from_bytey_buffer(buffer)
+    }
+
+    #[inline]
+    fn read_from_bytey_buffer_be<S: Source>(buffer: &mut S) -> Result<VarU32> {
+        Self::read_from_bytey_buffer(buffer)
+    }
+}
+
+impl ByteBufferRead for VarU64 {
+    #[inline]
+    fn read_from_bytey_buffer<S: Source>(buffer: &mut S) -> Result<VarU64> {
+        Ok(VarU64(varint::read_unsigned(|| buffer.read::<u8>(), u64::BITS, "VarU64")?))
+    }
+
+    #[inline]
+    fn read_from_bytey_buffer_le<S: Source>(buffer: &mut S) -> Result<VarU64> {
+        Self::read_from_bytey_buffer(buffer)
+    }
+
+    #[inline]
+    fn read_from_bytey_buffer_be<S: Source>(buffer: &mut S) -> Result<VarU64> {
+        Self::read_from_bytey_buffer(buffer)
+    }
+}
+
+impl ByteBufferRead for VarI32 {
+    #[inline]
+    fn read_from_bytey_buffer<S: Source>(buffer: &mut S) -> Result<VarI32> {
+        let value = varint::read_unsigned(|| buffer.read::<u8>(), u32::BITS, "VarI32")?;
+
+        Ok(VarI32(varint::zigzag_decode(value) as i32))
+    }
+
+    #[inline]
+    fn read_from_bytey_buffer_le<S: Source>(buffer: &mut S) -> Result<VarI32> {
+        Self::read_from_bytey_buffer(buffer)
+    }
+
+    #[inline]
+    fn read_from_bytey_buffer_be<S: Source>(buffer: &mut S) -> Result<VarI32> {
+        Self::read_from_bytey_buffer(buffer)
+    }
+}
+
+impl ByteBufferRead for VarI64 {
+    #[inline]
+    fn read_from_bytey_buffer<S: Source>(buffer: &mut S) -> Result<VarI64> {
+        let value = varint::read_unsigned(|| buffer.read::<u8>(), u64::BITS, "VarI64")?;
+
+        Ok(VarI64(varint::zigzag_decode(value)))
+    }
+
+    #[inline]
+    fn read_from_bytey_buffer_le<S: Source>(buffer: &mut S) -> Result<VarI64> {
+        Self::read_from_bytey_buffer(buffer)
+    }
+
+    #[inline]
+    fn read_from_bytey_buffer_be<S: Source>(buffer: &mut S) -> Result<VarI64> {
+        Self::read_from_bytey_buffer(buffer)
+    }
+}
@@ -0,0 +1,19 @@
+use crate::{byte_buffer_ref::ByteBufferRef, error::Result};
+
+mod arrays;
+mod option;
+mod string;
+
+/// Reads a value that can borrow from the bytes of a [`ByteBufferRef`].
+///
+/// The counterpart of [`ByteBufferRead`](crate::byte_buffer_read::ByteBufferRead) for zero-copy reads,
+/// values are read in the same format [`ByteBufferWrite`](crate::byte_buffer_write::ByteBufferWrite) writes them.
+///
+/// It is only implemented for the borrowing types `&str`, `&[u8]` and `Cow` of them, and for `Option` and `Vec`
+/// of borrowing types. Any other value is read with [`ByteBufferRead`](crate::byte_buffer_read::ByteBufferRead),
+/// as [`ByteBufferRef`] is a [`Source`](crate::stream::Source).
+pub trait ByteBufferReadRef<'a>: Sized {
+    fn read_ref_from_bytey_buffer(buffer: &mut ByteBufferRef<'a>) -> Result<Self>;
+    fn read_ref_from_bytey_buffer_le(buffer: &mut ByteBufferRef<'a>) -> Result<Self>;
+    fn read_ref_from_bytey_buffer_be(buffer: &mut ByteBufferRef<'a>) -> Result<Self>;
+}
@@ -0,0 +1,110 @@
+use crate::{
+    byte_buffer_read_ref::ByteBufferReadRef, byte_buffer_ref::ByteBufferRef,
+    decode_limits::preallocation, error::Result, length_prefix::Length, stream::Source,
+};
+use alloc::{borrow::Cow, vec::Vec};
+
+impl<'a: 'b, 'b> ByteBufferReadRef<'a> for &'b [u8] {
+    #[inline]
+    fn read_ref_from_bytey_buffer(buffer: &mut ByteBufferRef<'a>) -> Result<Self> {
+        let len = buffer.read::<Length>()?.0;
+
+        buffer.read_slice(len)
+    }
+
+    #[inline]
+    fn read_ref_from_bytey_buffer_le(buffer: &mut ByteBufferRef<'a>) -> Result<Self> {
+        let len = buffer.read_le::<Length>()?.0;
+
+        buffer.read_slice(len)
+    }
+
+    #[inline]
+    fn read_ref_from_bytey_buffer_be(buffer: &mut ByteBufferRef<'a>) -> Result<Self> {
+        let len = buffer.read_be::<Length>()?.0;
+
+        buffer.read_slice(len)
+    }
+}
+
+impl<'a: 'b, 'b> ByteBufferReadRef<'a> for Cow<'b, [u8]> {
+    #[inline]
+    fn read_ref_from_bytey_buffer(buffer: &mut ByteBufferRef<'a>) -> Result<Self> {
+        Ok(Cow::Borrowed(buffer.read_ref::<&[u8]>()?))
+    }
+
+    #[inline]
+    fn read_ref_from_bytey_buffer_le(buffer: &mut ByteBufferRef<'a>) -> Result<Self> {
+        Ok(Cow::Borrowed(buffer.read_ref_le::<&[u8]>()?))
+    }
+
+    #[inline]
+    fn read_ref_from_bytey_buffer_be(buffer: &mut ByteBufferRef<'a>) -> Result<Self> {
+        Ok(Cow::Borrowed(buffer.read_ref_be::<&[u8]>()?))
+    }
+}
+
+impl<'a, T: ByteBufferReadRef<'a>> ByteBufferReadRef<'a> for Vec<T> {
+    #[inline]
+    fn read_ref_from_bytey_buffer(buffer: &mut ByteBufferRef<'a>) -> Result<Self> {
+        let size = buffer.read::<Length>()?.0;
+
+        buffer.read_nested(|buffer| {
+            buffer.limit_collection::<T>(size)?;
+
+            let mut vec = Vec::with_capacity(preallocation::<T>(size));
+
+            for index in 0..size {
+                vec.push(
+                    buffer
+                        .read_ref::<T>()
+                        .map_err(|error| error.with_index(index, buffer.offset()))?,
+                );
+            }
+
+            Ok(vec)
+        })
+    }
+
+    #[inline]
+    fn read_ref_from_bytey_buffer_le(buffer: &mut ByteBufferRef<'a>) -> Result<Self> {
+        let size = buffer.read_le::<Length>()?.0;
+
+        buffer.read_nested(|buffer| {
+            buffer.limit_collection::<T>(size)?;
+
+            let mut vec = Vec::with_capacity(preallocation::<T>(size));
+
+            for index in 0..size {
+                vec.push(
+                    buffer
+                        .read_ref_le::<T>()
+                        .map_err(|error| error.with_index(index, buffer.offset()))?,
+                );
+            }
+
+            Ok(vec)
+        })
+    }
+
+    #[inline]
+    fn read_ref_from_bytey_buffer_be(buffer: &mut ByteBufferRef<'a>) -> Result<Self> {
+        let size = buffer.read_be::<Length>()?.0;
+
+        buffer.read_nested(|buffer| {
+            buffer.limit_collection::<T>(size)?;
+
+            let mut vec = Vec::with_capacity(preallocation::<T>(size));
+
+            for index in 0..size {
+                vec.push(
+                    buffer
+                        .read_ref_be::<T>()
+                        .map_err(|error| error.with_index(index, buffer.offset()))?,
+                );
+            }
+
+            Ok(vec)
+        })
+    }
+}
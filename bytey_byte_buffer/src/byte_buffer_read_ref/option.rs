@@ -0,0 +1,43 @@
+use crate::{
+    byte_buffer_read_ref::ByteBufferReadRef,
+    byte_buffer_ref::ByteBufferRef,
+    error::{ByteBufferError, Result},
+};
+
+impl<'a, T: ByteBufferReadRef<'a>> ByteBufferReadRef<'a> for Option<T> {
+    #[inline]
+    fn read_ref_from_bytey_buffer(buffer: &mut ByteBufferRef<'a>) -> Result<Option<T>> {
+        match buffer.read::<u8>()? {
+            1 => Ok(Some(buffer.read_ref::<T>()?)),
+            2 => Ok(None),
+            tag => Err(ByteBufferError::InvalidTag {
+                type_name: "Option",
+                tag: tag.into(),
+            }),
+        }
+    }
+
+    #[inline]
+    fn read_ref_from_bytey_buffer_le(buffer: &mut ByteBufferRef<'a>) -> Result<Option<T>> {
+        match buffer.read_le::<u8>()? {
+            1 => Ok(Some(buffer.read_ref_le::<T>()?)),
+            2 => Ok(None),
+            tag => Err(ByteBufferError::InvalidTag {
+                type_name: "Option",
+                tag: tag.into(),
+            }),
+        }
+    }
+
+    #[inline]
+    fn read_ref_from_bytey_buffer_be(buffer: &mut ByteBufferRef<'a>) -> Result<Option<T>> {
+        match buffer.read_be::<u8>()? {
+            1 => Ok(Some(buffer.read_ref_be::<T>()?)),
+            2 => Ok(None),
+            tag => Err(ByteBufferError::InvalidTag {
+                type_name: "Option",
+                tag: tag.into(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{
+    byte_buffer_read_ref::ByteBufferReadRef, byte_buffer_ref::ByteBufferRef, error::Result,
+    length_prefix::Length,
+};
+use alloc::borrow::Cow;
+
+impl<'a: 'b, 'b> ByteBufferReadRef<'a> for &'b str {
+    #[inline]
+    fn read_ref_from_bytey_buffer(buffer: &mut ByteBufferRef<'a>) -> Result<Self> {
+        let len = buffer.read::<Length>()?.0;
+
+        Ok(core::str::from_utf8(buffer.read_slice(len)?)?)
+    }
+
+    #[inline]
+    fn read_ref_from_bytey_buffer_le(buffer: &mut ByteBufferRef<'a>) -> Result<Self> {
+        let len = buffer.read_le::<Length>()?.0;
+
+        Ok(core::str::from_utf8(buffer.read_slice(len)?)?)
+    }
+
+    #[inline]
+    fn read_ref_from_bytey_buffer_be(buffer: &mut ByteBufferRef<'a>) -> Result<Self> {
+        let len = buffer.read_be::<Length>()?.0;
+
+        Ok(core::str::from_utf8(buffer.read_slice(len)?)?)
+    }
+}
+
+impl<'a: 'b, 'b> ByteBufferReadRef<'a> for Cow<'b, str> {
+    #[inline]
+    fn read_ref_from_bytey_buffer(buffer: &mut ByteBufferRef<'a>) -> Result<Self> {
+        Ok(Cow::Borrowed(buffer.read_ref::<&str>()?))
+    }
+
+    #[inline]
+    fn read_ref_from_bytey_buffer_le(buffer: &mut ByteBufferRef<'a>) -> Result<Self> {
+        Ok(Cow::Borrowed(buffer.read_ref_le::<&str>()?))
+    }
+
+    #[inline]
+    fn read_ref_from_bytey_buffer_be(buffer: &mut ByteBufferRef<'a>) -> Result<Self> {
+        Ok(Cow::Borrowed(buffer.read_ref_be::<&str>()?))
+    }
+}
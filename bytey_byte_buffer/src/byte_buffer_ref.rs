@@ -0,0 +1,249 @@
+use crate::byte_buffer_read::ByteBufferRead;
+use crate::byte_buffer_read_ref::ByteBufferReadRef;
+use crate::decode_limits::DecodeLimits;
+use crate::error::{ByteBufferError, Result};
+use crate::length_prefix::LengthPrefix;
+use crate::stream::Source;
+
+/// A read only view of bytes with its own cursor, used for zero-copy reads.
+///
+/// A [`ByteBufferRef`] is a [`Source`], so every [`ByteBufferRead`] value is read from it with
+/// [`read`](Self::read). Values implementing [`ByteBufferReadRef`] are read with [`read_ref`](Self::read_ref) and
+/// can borrow from the underlying bytes, e.g. `&'a str` and `&'a [u8]` are returned without copying.
+///
+/// A [`ByteBufferRef`] is created from a [`ByteBuffer`](crate::byte_buffer::ByteBuffer) with
+/// [`reader`](crate::byte_buffer::ByteBuffer::reader) or from any byte slice with [`new`](Self::new).
+///
+/// # Examples
+/// ```
+/// use bytey_byte_buffer::byte_buffer::ByteBuffer;
+///
+/// let mut buffer = ByteBuffer::new().unwrap();
+///
+/// buffer.write("hello").unwrap();
+/// buffer.write(1234u16).unwrap();
+/// buffer.move_cursor(0).unwrap();
+///
+/// let mut reader = buffer.reader();
+/// let text: &str = reader.read_ref().unwrap();
+///
+/// assert_eq!(text, "hello");
+/// assert_eq!(reader.read::<u16>().unwrap(), 1234);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ByteBufferRef<'a> {
+    data: &'a [u8],
+    cursor: usize,
+    length_prefix: LengthPrefix,
+    canonical: bool,
+    decode_limits: DecodeLimits,
+}
+
+impl<'a> ByteBufferRef<'a> {
+    /// Constructs a new [`ByteBufferRef`] reading the given bytes from the start.
+    ///
+    /// # Examples
+    /// ```
+    /// use bytey_byte_buffer::byte_buffer_ref::ByteBufferRef;
+    ///
+    /// let mut reader = ByteBufferRef::new(&[1, 0]);
+    ///
+    /// assert_eq!(reader.read_le::<u16>().unwrap(), 1);
+    /// ```
+    pub fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            cursor: 0,
+            length_prefix: LengthPrefix::default(),
+            canonical: false,
+            decode_limits: DecodeLimits::new(),
+        }
+    }
+
+    pub(crate) fn with_cursor(
+        data: &'a [u8],
+        cursor: usize,
+        length_prefix: LengthPrefix,
+        canonical: bool,
+        decode_limits: DecodeLimits,
+    ) -> Self {
+        Self {
+            data,
+            cursor,
+            length_prefix,
+            canonical,
+            decode_limits,
+        }
+    }
+
+    /// Reads a slice of type [u8] of the given size, the slice borrows from the underlying bytes.
+    ///
+    /// # Behaviour
+    /// The current cursor position will be increased by the given size.
+    ///
+    /// # Errors
+    /// - [`ByteBufferError::ReadOutOfBounds`] is returned if the result of the current cursor position + the given size exceeds the length
+    pub fn read_slice(&mut self, size: usize) -> Result<&'a [u8]> {
+        let end = match self.cursor.checked_add(size) {
+            Some(end) if end <= self.data.len() => end,
+            _ => {
+                return Err(ByteBufferError::ReadOutOfBounds {
+                    length: self.data.len(),
+                    start: self.cursor,
+                    end: self.cursor.saturating_add(size),
+                });
+            }
+        };
+
+        let ret = &self.data[self.cursor..end];
+        self.cursor = end;
+
+        Ok(ret)
+    }
+
+    /// Reads a value of type T that implements the [`ByteBufferRead`] trait.
+    ///
+    /// # Errors & Behaviour
+    /// See [`read_slice`](Self::read_slice).
+    pub fn read<T: ByteBufferRead>(&mut self) -> Result<T> {
+        T::read_from_bytey_buffer(self)
+    }
+
+    /// Reads a value of type T that implements the [`ByteBufferRead`] trait in **little endian** ordering.
+    ///
+    /// # Errors & Behaviour
+    /// See [`read_slice`](Self::read_slice).
+    pub fn read_le<T: ByteBufferRead>(&mut self) -> Result<T> {
+        T::read_from_bytey_buffer_le(self)
+    }
+
+    /// Reads a value of type T that implements the [`ByteBufferRead`] trait in **big endian** ordering.
+    ///
+    /// # Errors & Behaviour
+    /// See [`read_slice`](Self::read_slice).
+    pub fn read_be<T: ByteBufferRead>(&mut self) -> Result<T> {
+        T::read_from_bytey_buffer_be(self)
+    }
+
+    /// Reads a value of type T that implements the [`ByteBufferReadRef`] trait, which can borrow from the bytes.
+    ///
+    /// # Errors & Behaviour
+    /// See [`read_slice`](Self::read_slice).
+    pub fn read_ref<T: ByteBufferReadRef<'a>>(&mut self) -> Result<T> {
+        T::read_ref_from_bytey_buffer(self)
+    }
+
+    /// Reads a value of type T that implements the [`ByteBufferReadRef`] trait in **little endian** ordering.
+    ///
+    /// # Errors & Behaviour
+    /// See [`read_slice`](Self::read_slice).
+    pub fn read_ref_le<T: ByteBufferReadRef<'a>>(&mut self) -> Result<T> {
+        T::read_ref_from_bytey_buffer_le(self)
+    }
+
+    /// Reads a value of type T that implements the [`ByteBufferReadRef`] trait in **big endian** ordering.
+    ///
+    /// # Errors & Behaviour
+    /// See [`read_slice`](Self::read_slice).
+    pub fn read_ref_be<T: ByteBufferReadRef<'a>>(&mut self) -> Result<T> {
+        T::read_ref_from_bytey_buffer_be(self)
+    }
+
+    /// Moves the current cursor position.
+    ///
+    /// # Errors
+    /// - [`ByteBufferError::CursorOutOfBounds`] if the cursor exceeds the length
+    pub fn move_cursor(&mut self, location: usize) -> Result<&mut Self> {
+        if location > self.data.len() {
+            return Err(ByteBufferError::CursorOutOfBounds {
+                length: self.data.len(),
+                cursor: location,
+            });
+        }
+
+        self.cursor = location;
+
+        Ok(self)
+    }
+
+    /// Returns the total amount of bytes, regardless of the cursor position.
+    pub fn length(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns the current cursor position.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Returns true if the length is 0
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the [`LengthPrefix`] used to read the length of strings and collections.
+    pub fn length_prefix(&self) -> LengthPrefix {
+        self.length_prefix
+    }
+
+    /// Sets the [`LengthPrefix`] used to read the length of strings and collections.
+    pub fn set_length_prefix(&mut self, length_prefix: LengthPrefix) -> &mut Self {
+        self.length_prefix = length_prefix;
+
+        self
+    }
+
+    /// Returns true if maps and sets with duplicate keys are rejected, see
+    /// [`ByteBuffer::set_canonical`](crate::byte_buffer::ByteBuffer::set_canonical).
+    pub fn canonical(&self) -> bool {
+        self.canonical
+    }
+
+    /// Sets whether maps and sets with duplicate keys are rejected, see
+    /// [`ByteBuffer::set_canonical`](crate::byte_buffer::ByteBuffer::set_canonical).
+    pub fn set_canonical(&mut self, canonical: bool) -> &mut Self {
+        self.canonical = canonical;
+
+        self
+    }
+
+    /// Returns the [`DecodeLimits`] enforced while reading values.
+    pub fn decode_limits(&self) -> DecodeLimits {
+        self.decode_limits
+    }
+
+    /// Sets the [`DecodeLimits`] enforced while reading values, see
+    /// [`ByteBuffer::set_decode_limits`](crate::byte_buffer::ByteBuffer::set_decode_limits).
+    pub fn set_decode_limits(&mut self, decode_limits: DecodeLimits) -> &mut Self {
+        self.decode_limits = decode_limits.reset();
+
+        self
+    }
+}
+
+impl Source for ByteBufferRef<'_> {
+    #[inline]
+    fn read_slice(&mut self, size: usize) -> Result<&[u8]> {
+        ByteBufferRef::read_slice(self, size)
+    }
+
+    #[inline]
+    fn length_prefix(&self) -> LengthPrefix {
+        self.length_prefix
+    }
+
+    #[inline]
+    fn canonical(&self) -> bool {
+        self.canonical
+    }
+
+    #[inline]
+    fn offset(&self) -> Option<usize> {
+        Some(self.cursor)
+    }
+
+    #[inline]
+    fn decode_limits_mut(&mut self) -> Option<&mut DecodeLimits> {
+        Some(&mut self.decode_limits)
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{byte_buffer::ByteBuffer, error::Result};
+use crate::{error::Result, stream::Sink};
 
 mod arrays;
 mod bound;
@@ -6,20 +6,24 @@ mod boxed;
 mod byte;
 mod cell;
 mod char;
+mod collections;
 mod cow;
+mod length;
 mod num;
 mod option;
 mod phantom;
 mod range;
 mod result;
+mod shared;
 mod string;
 mod time;
 mod tuple;
+mod varint;
 
 pub trait ByteBufferWrite {
-    fn write_to_bytey_buffer(&self, buffer: &mut ByteBuffer) -> Result<()>;
-    fn write_to_bytey_buffer_le(&self, buffer: &mut ByteBuffer) -> Result<()>;
-    fn write_to_bytey_buffer_be(&self, buffer: &mut ByteBuffer) -> Result<()>;
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()>;
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()>;
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()>;
 }
 
 macro_rules! impl_byte_buffer_write_types {
@@ -27,41 +31,41 @@ macro_rules! impl_byte_buffer_write_types {
         $(
             impl ByteBufferWrite for $type {
                 #[inline]
-                fn write_to_bytey_buffer(&self, buffer: &mut ByteBuffer) -> Result<()> {
+                fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
                     unsafe {
-                        buffer.write_slice(std::slice::from_raw_parts(self as *const $type as *const u8, std::mem::size_of::<$type>()))?;
+                        buffer.write_slice(core::slice::from_raw_parts(self as *const $type as *const u8, core::mem::size_of::<$type>()))?;
                     }
 
                     Ok(())
                 }
 
                 #[inline]
-                fn write_to_bytey_buffer_le(&self, buffer: &mut ByteBuffer) -> Result<()> {
+                fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
                     #[cfg(target_endian = "little")]
                     unsafe {
-                        buffer.write_slice(std::slice::from_raw_parts(self as *const $type as *const u8, std::mem::size_of::<$type>()))?;
+                        buffer.write_slice(core::slice::from_raw_parts(self as *const $type as *const u8, core::mem::size_of::<$type>()))?;
                     }
 
                     #[cfg(not(target_endian = "little"))]
                     unsafe {
                         let o = self.to_le_bytes();
-                        buffer.write_slice(std::slice::from_raw_parts(&o as *const u8, std::mem::size_of::<$type>()))?;
+                        buffer.write_slice(core::slice::from_raw_parts(&o as *const u8, core::mem::size_of::<$type>()))?;
                     }
 
                     Ok(())
                 }
 
                 #[inline]
-                fn write_to_bytey_buffer_be(&self, buffer: &mut ByteBuffer) -> Result<()> {
+                fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
                     #[cfg(target_endian = "big")]
                     unsafe {
-                        buffer.write_slice(std::slice::from_raw_parts(self as *const $type as *const u8, std::mem::size_of::<$type>()))?;
+                        buffer.write_slice(core::slice::from_raw_parts(self as *const $type as *const u8, core::mem::size_of::<$type>()))?;
                     }
 
                     #[cfg(not(target_endian = "big"))]
                     unsafe {
                         let o = self.to_be_bytes();
-                        buffer.write_slice(std::slice::from_raw_parts(&o as *const u8, std::mem::size_of::<$type>()))?;
+                        buffer.write_slice(core::slice::from_raw_parts(&o as *const u8, core::mem::size_of::<$type>()))?;
                     }
 
                     Ok(())
@@ -70,41 +74,41 @@ macro_rules! impl_byte_buffer_write_types {
 
             impl ByteBufferWrite for &$type {
                 #[inline]
-                fn write_to_bytey_buffer(&self, buffer: &mut ByteBuffer) -> Result<()> {
+                fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
                     unsafe {
-                        buffer.write_slice(std::slice::from_raw_parts(*self as *const $type as *const u8, std::mem::size_of::<$type>()))?;
+                        buffer.write_slice(core::slice::from_raw_parts(*self as *const $type as *const u8, core::mem::size_of::<$type>()))?;
                     }
 
                     Ok(())
                 }
 
                 #[inline]
-                fn write_to_bytey_buffer_le(&self, buffer: &mut ByteBuffer) -> Result<()> {
+                fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
                     #[cfg(target_endian = "little")]
                     unsafe {
-                        buffer.write_slice(std::slice::from_raw_parts(*self as *const $type as *const u8, std::mem::size_of::<$type>()))?;
+                        buffer.write_slice(core::slice::from_raw_parts(*self as *const $type as *const u8, core::mem::size_of::<$type>()))?;
                     }
 
                     #[cfg(not(target_endian = "little"))]
                     unsafe {
                         let o = self.to_le_bytes();
-                        buffer.write_slice(std::slice::from_raw_parts(&o as *const u8, std::mem::size_of::<$type>()))?;
+                        buffer.write_slice(core::slice::from_raw_parts(&o as *const u8, core::mem::size_of::<$type>()))?;
                     }
 
                     Ok(())
                 }
 
                 #[inline]
-                fn write_to_bytey_buffer_be(&self, buffer: &mut ByteBuffer) -> Result<()> {
+                fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
                     #[cfg(target_endian = "big")]
                     unsafe {
-                        buffer.write_slice(std::slice::from_raw_parts(*self as *const $type as *const u8, std::mem::size_of::<$type>()))?;
+                        buffer.write_slice(core::slice::from_raw_parts(*self as *const $type as *const u8, core::mem::size_of::<$type>()))?;
                     }
 
                     #[cfg(not(target_endian = "big"))]
                     unsafe {
                         let o = self.to_be_bytes();
-                        buffer.write_slice(std::slice::from_raw_parts(&o as *const u8, std::mem::size_of::<$type>()))?;
+                        buffer.write_slice(core::slice::from_raw_parts(&o as *const u8, core::mem::size_of::<$type>()))?;
                     }
 
                     Ok(())
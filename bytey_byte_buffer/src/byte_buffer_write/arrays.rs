@@ -1,9 +1,15 @@
-use crate::{byte_buffer::ByteBuffer, byte_buffer_write::ByteBufferWrite, error::Result};
+use crate::{
+    byte_buffer_write::ByteBufferWrite,
+    error::Result,
+    length_prefix::Length,
+    stream::Sink,
+};
+use alloc::vec::Vec;
 
 impl<T: ByteBufferWrite, const N: usize> ByteBufferWrite for [T; N] {
     #[inline]
-    fn write_to_bytey_buffer(&self, buffer: &mut ByteBuffer) -> Result<()> {
-        (self.len() as u64).write_to_bytey_buffer(buffer)?;
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        Length(self.len()).write_to_bytey_buffer(buffer)?;
 
         for e in self {
             e.write_to_bytey_buffer(buffer)?;
@@ -13,8 +19,8 @@ impl<T: ByteBufferWrite, const N: usize> ByteBufferWrite for [T; N] {
     }
 
     #[inline]
-    fn write_to_bytey_buffer_le(&self, buffer: &mut ByteBuffer) -> Result<()> {
-        (self.len() as u64).write_to_bytey_buffer_le(buffer)?;
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        Length(self.len()).write_to_bytey_buffer_le(buffer)?;
 
         for e in self {
             e.write_to_bytey_buffer_le(buffer)?;
@@ -24,8 +30,8 @@ impl<T: ByteBufferWrite, const N: usize> ByteBufferWrite for [T; N] {
     }
 
     #[inline]
-    fn write_to_bytey_buffer_be(&self, buffer: &mut ByteBuffer) -> Result<()> {
-        (self.len() as u64).write_to_bytey_buffer_be(buffer)?;
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        Length(self.len()).write_to_bytey_buffer_be(buffer)?;
 
         for e in self {
             e.write_to_bytey_buffer_be(buffer)?;
@@ -37,8 +43,8 @@ impl<T: ByteBufferWrite, const N: usize> ByteBufferWrite for [T; N] {
 
 impl<T: ByteBufferWrite> ByteBufferWrite for [T] {
     #[inline]
-    fn write_to_bytey_buffer(&self, buffer: &mut ByteBuffer) -> Result<()> {
-        (self.len() as u64).write_to_bytey_buffer(buffer)?;
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        Length(self.len()).write_to_bytey_buffer(buffer)?;
 
         for e in self {
             e.write_to_bytey_buffer(buffer)?;
@@ -48,8 +54,8 @@ impl<T: ByteBufferWrite> ByteBufferWrite for [T] {
     }
 
     #[inline]
-    fn write_to_bytey_buffer_le(&self, buffer: &mut ByteBuffer) -> Result<()> {
-        (self.len() as u64).write_to_bytey_buffer_le(buffer)?;
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        Length(self.len()).write_to_bytey_buffer_le(buffer)?;
 
         for e in self {
             e.write_to_bytey_buffer_le(buffer)?;
@@ -59,8 +65,8 @@ impl<T: ByteBufferWrite> ByteBufferWrite for [T] {
     }
 
     #[inline]
-    fn write_to_bytey_buffer_be(&self, buffer: &mut ByteBuffer) -> Result<()> {
-        (self.len() as u64).write_to_bytey_buffer_be(buffer)?;
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        Length(self.len()).write_to_bytey_buffer_be(buffer)?;
 
         for e in self {
             e.write_to_bytey_buffer_be(buffer)?;
@@ -72,8 +78,8 @@ impl<T: ByteBufferWrite> ByteBufferWrite for [T] {
 
 impl<T: ByteBufferWrite> ByteBufferWrite for Vec<T> {
     #[inline]
-    fn write_to_bytey_buffer(&self, buffer: &mut ByteBuffer) -> Result<()> {
-        (self.len() as u64).write_to_bytey_buffer(buffer)?;
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        Length(self.len()).write_to_bytey_buffer(buffer)?;
 
         for e in self {
             e.write_to_bytey_buffer(buffer)?;
@@ -83,8 +89,8 @@ impl<T: ByteBufferWrite> ByteBufferWrite for Vec<T> {
     }
 
     #[inline]
-    fn write_to_bytey_buffer_le(&self, buffer: &mut ByteBuffer) -> Result<()> {
-        (self.len() as u64).write_to_bytey_buffer_le(buffer)?;
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        Length(self.len()).write_to_bytey_buffer_le(buffer)?;
 
         for e in self {
             e.write_to_bytey_buffer_le(buffer)?;
@@ -94,8 +100,8 @@ impl<T: ByteBufferWrite> ByteBufferWrite for Vec<T> {
     }
 
     #[inline]
-    fn write_to_bytey_buffer_be(&self, buffer: &mut ByteBuffer) -> Result<()> {
-        (self.len() as u64).write_to_bytey_buffer_be(buffer)?;
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        Length(self.len()).write_to_bytey_buffer_be(buffer)?;
 
         for e in self {
             e.write_to_bytey_buffer_be(buffer)?;
@@ -107,8 +113,8 @@ impl<T: ByteBufferWrite> ByteBufferWrite for Vec<T> {
 
 impl<T: ByteBufferWrite> ByteBufferWrite for &Vec<T> {
     #[inline]
-    fn write_to_bytey_buffer(&self, buffer: &mut ByteBuffer) -> Result<()> {
-        (self.len() as u64).write_to_bytey_buffer(buffer)?;
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        Length(self.len()).write_to_bytey_buffer(buffer)?;
 
         for e in *self {
             e.write_to_bytey_buffer(buffer)?;
@@ -118,8 +124,8 @@ impl<T: ByteBufferWrite> ByteBufferWrite for &Vec<T> {
     }
 
     #[inline]
-    fn write_to_bytey_buffer_le(&self, buffer: &mut ByteBuffer) -> Result<()> {
-        (self.len() as u64).write_to_bytey_buffer_le(buffer)?;
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        Length(self.len()).write_to_bytey_buffer_le(buffer)?;
 
         for e in *self {
             e.write_to_bytey_buffer_le(buffer)?;
@@ -129,8 +135,8 @@ impl<T: ByteBufferWrite> ByteBufferWrite for &Vec<T> {
     }
 
     #[inline]
-    fn write_to_bytey_buffer_be(&self, buffer: &mut ByteBuffer) -> Result<()> {
-        (self.len() as u64).write_to_bytey_buffer_be(buffer)?;
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        Length(self.len()).write_to_bytey_buffer_be(buffer)?;
 
         for e in *self {
             e.write_to_bytey_buffer_be(buffer)?;
@@ -1,9 +1,9 @@
-use crate::{byte_buffer::ByteBuffer, byte_buffer_write::ByteBufferWrite, error::Result};
-use std::ops::Bound;
+use crate::{byte_buffer_write::ByteBufferWrite, error::Result, stream::Sink};
+use core::ops::Bound;
 
 impl<T: ByteBufferWrite> ByteBufferWrite for Bound<T> {
     #[inline]
-    fn write_to_bytey_buffer(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         match self {
             Self::Unbounded => 0u8.write_to_bytey_buffer(buffer),
             Self::Included(val) => {
@@ -18,7 +18,7 @@ impl<T: ByteBufferWrite> ByteBufferWrite for Bound<T> {
     }
 
     #[inline]
-    fn write_to_bytey_buffer_le(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         match self {
             Self::Unbounded => 0u8.write_to_bytey_buffer_le(buffer),
             Self::Included(val) => {
@@ -33,7 +33,7 @@ impl<T: ByteBufferWrite> ByteBufferWrite for Bound<T> {
     }
 
     #[inline]
-    fn write_to_bytey_buffer_be(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         match self {
             Self::Unbounded => 0u8.write_to_bytey_buffer_be(buffer),
             Self::Included(val) => {
@@ -1,18 +1,20 @@
-use crate::{byte_buffer::ByteBuffer, byte_buffer_write::ByteBufferWrite, error::Result};
+use crate::{byte_buffer_write::ByteBufferWrite, error::Result, stream::Sink};
+use alloc::boxed::Box;
 
-impl<T: ByteBufferWrite> ByteBufferWrite for Box<T> {
+/// Also covers `Box<str>` and `Box<[T]>`, which are written like `str` and `[T]`.
+impl<T: ByteBufferWrite + ?Sized> ByteBufferWrite for Box<T> {
     #[inline]
-    fn write_to_bytey_buffer(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         self.as_ref().write_to_bytey_buffer(buffer)
     }
 
     #[inline]
-    fn write_to_bytey_buffer_le(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         self.as_ref().write_to_bytey_buffer_le(buffer)
     }
 
     #[inline]
-    fn write_to_bytey_buffer_be(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         self.as_ref().write_to_bytey_buffer_be(buffer)
     }
 }
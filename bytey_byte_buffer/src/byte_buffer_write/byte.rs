@@ -1,9 +1,9 @@
-use crate::{byte_buffer::ByteBuffer, byte_buffer_write::ByteBufferWrite, error::Result};
-use std::slice;
+use crate::{byte_buffer_write::ByteBufferWrite, error::Result, stream::Sink};
+use core::slice;
 
 impl ByteBufferWrite for u8 {
     #[inline]
-    fn write_to_bytey_buffer(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         unsafe {
             buffer.write_slice(slice::from_raw_parts(self as *const u8, 1))?;
         }
@@ -12,19 +12,19 @@ impl ByteBufferWrite for u8 {
     }
 
     #[inline]
-    fn write_to_bytey_buffer_le(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         self.write_to_bytey_buffer(buffer)
     }
 
     #[inline]
-    fn write_to_bytey_buffer_be(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         self.write_to_bytey_buffer(buffer)
     }
 }
 
 impl ByteBufferWrite for &u8 {
     #[inline]
-    fn write_to_bytey_buffer(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         unsafe {
             buffer.write_slice(slice::from_raw_parts(*self as *const u8, 1))?;
         }
@@ -33,19 +33,19 @@ impl ByteBufferWrite for &u8 {
     }
 
     #[inline]
-    fn write_to_bytey_buffer_le(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         self.write_to_bytey_buffer(buffer)
     }
 
     #[inline]
-    fn write_to_bytey_buffer_be(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         self.write_to_bytey_buffer(buffer)
     }
 }
 
 impl ByteBufferWrite for i8 {
     #[inline]
-    fn write_to_bytey_buffer(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         unsafe {
             buffer.write_slice(slice::from_raw_parts(self as *const i8 as *const u8, 1))?;
         }
@@ -54,19 +54,19 @@ impl ByteBufferWrite for i8 {
     }
 
     #[inline]
-    fn write_to_bytey_buffer_le(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         self.write_to_bytey_buffer(buffer)
     }
 
     #[inline]
-    fn write_to_bytey_buffer_be(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         self.write_to_bytey_buffer(buffer)
     }
 }
 
 impl ByteBufferWrite for &i8 {
     #[inline]
-    fn write_to_bytey_buffer(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         unsafe {
             buffer.write_slice(slice::from_raw_parts(*self as *const i8 as *const u8, 1))?;
         }
@@ -75,19 +75,19 @@ impl ByteBufferWrite for &i8 {
     }
 
     #[inline]
-    fn write_to_bytey_buffer_le(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         self.write_to_bytey_buffer(buffer)
     }
 
     #[inline]
-    fn write_to_bytey_buffer_be(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         self.write_to_bytey_buffer(buffer)
     }
 }
 
 impl ByteBufferWrite for bool {
     #[inline]
-    fn write_to_bytey_buffer(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         let data = if *self { 1u8 } else { 0u8 };
         buffer.write(data)?;
 
@@ -95,19 +95,19 @@ impl ByteBufferWrite for bool {
     }
 
     #[inline]
-    fn write_to_bytey_buffer_le(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         self.write_to_bytey_buffer(buffer)
     }
 
     #[inline]
-    fn write_to_bytey_buffer_be(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         self.write_to_bytey_buffer(buffer)
     }
 }
 
 impl ByteBufferWrite for &bool {
     #[inline]
-    fn write_to_bytey_buffer(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         let data = if **self { 1u8 } else { 0u8 };
         buffer.write(data)?;
 
@@ -115,12 +115,12 @@ impl ByteBufferWrite for &bool {
     }
 
     #[inline]
-    fn write_to_bytey_buffer_le(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         self.write_to_bytey_buffer(buffer)
     }
 
     #[inline]
-    fn write_to_bytey_buffer_be(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         self.write_to_bytey_buffer(buffer)
     }
 }
@@ -1,30 +1,31 @@
 use crate::{
-    byte_buffer::ByteBuffer,
     byte_buffer_write::ByteBufferWrite,
     error::{ByteBufferError, Result},
+    stream::Sink,
 };
-use std::cell::{Cell, RefCell};
+use alloc::string::ToString;
+use core::cell::{Cell, RefCell};
 
 impl<T: ByteBufferWrite + Copy> ByteBufferWrite for Cell<T> {
     #[inline]
-    fn write_to_bytey_buffer(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         self.get().write_to_bytey_buffer(buffer)
     }
 
     #[inline]
-    fn write_to_bytey_buffer_le(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         self.get().write_to_bytey_buffer_le(buffer)
     }
 
     #[inline]
-    fn write_to_bytey_buffer_be(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         self.get().write_to_bytey_buffer_be(buffer)
     }
 }
 
 impl<T: ByteBufferWrite + ?Sized> ByteBufferWrite for RefCell<T> {
     #[inline]
-    fn write_to_bytey_buffer(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         self.try_borrow()
             .map_err(|e| ByteBufferError::RefCellAlreadyBorrowed {
                 error: e.to_string(),
@@ -34,7 +35,7 @@ impl<T: ByteBufferWrite + ?Sized> ByteBufferWrite for RefCell<T> {
     }
 
     #[inline]
-    fn write_to_bytey_buffer_le(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         self.try_borrow()
             .map_err(|e| ByteBufferError::RefCellAlreadyBorrowed {
                 error: e.to_string(),
@@ -44,7 +45,7 @@ impl<T: ByteBufferWrite + ?Sized> ByteBufferWrite for RefCell<T> {
     }
 
     #[inline]
-    fn write_to_bytey_buffer_be(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         self.try_borrow()
             .map_err(|e| ByteBufferError::RefCellAlreadyBorrowed {
                 error: e.to_string(),
@@ -1,18 +1,18 @@
-use crate::{byte_buffer::ByteBuffer, byte_buffer_write::ByteBufferWrite, error::Result};
+use crate::{byte_buffer_write::ByteBufferWrite, error::Result, stream::Sink};
 
 impl ByteBufferWrite for char {
     #[inline]
-    fn write_to_bytey_buffer(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         u32::from(*self).write_to_bytey_buffer(buffer)
     }
 
     #[inline]
-    fn write_to_bytey_buffer_le(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         u32::from(*self).write_to_bytey_buffer_le(buffer)
     }
 
     #[inline]
-    fn write_to_bytey_buffer_be(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         u32::from(*self).write_to_bytey_buffer_be(buffer)
     }
 }
@@ -0,0 +1,206 @@
+use crate::{
+    byte_buffer::ByteBuffer, byte_buffer_write::ByteBufferWrite, error::Result,
+    length_prefix::Length, stream::Sink,
+};
+use alloc::{
+    collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque},
+    vec::Vec,
+};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+
+/// Writes the entries sorted by the encoded bytes of their key, for [`Sink::canonical`].
+///
+/// Every entry is first written to a scratch buffer with the settings of the sink by `write_key` and
+/// `write_value`, then the entries are copied to the sink in order.
+fn write_sorted<S: Sink, E: Copy>(
+    buffer: &mut S,
+    entries: impl Iterator<Item = E>,
+    write_key: impl Fn(E, &mut ByteBuffer) -> Result<()>,
+    write_value: impl Fn(E, &mut ByteBuffer) -> Result<()>,
+) -> Result<()> {
+    let mut scratch = ByteBuffer::new()?;
+    scratch
+        .set_length_prefix(buffer.length_prefix())
+        .set_canonical(true);
+
+    // The start of the entry, the end of its key and the end of the entry in the scratch buffer.
+    let mut ranges = Vec::new();
+
+    for entry in entries {
+        let start = scratch.length();
+        write_key(entry, &mut scratch)?;
+        let key_end = scratch.length();
+        write_value(entry, &mut scratch)?;
+
+        ranges.push((start, key_end, scratch.length()));
+    }
+
+    let bytes = scratch.as_bytes();
+    ranges.sort_by(|a, b| bytes[a.0..a.1].cmp(&bytes[b.0..b.1]));
+
+    for (start, _, end) in ranges {
+        buffer.write_slice(&bytes[start..end])?;
+    }
+
+    Ok(())
+}
+
+/// Implements [`ByteBufferWrite`] for a reference to a collection by writing the collection.
+macro_rules! impl_write_ref {
+    ([$($generics:tt)*] $type:ty) => {
+        impl<$($generics)*> ByteBufferWrite for &$type {
+            #[inline]
+            fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+                (*self).write_to_bytey_buffer(buffer)
+            }
+
+            #[inline]
+            fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+                (*self).write_to_bytey_buffer_le(buffer)
+            }
+
+            #[inline]
+            fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+                (*self).write_to_bytey_buffer_be(buffer)
+            }
+        }
+    };
+}
+
+/// Implements [`ByteBufferWrite`] for a collection and a reference to it, written as the [`Length`] followed by
+/// every element in iteration order.
+macro_rules! impl_write_sequence {
+    ($([$($generics:tt)*] $type:ty;)*) => {
+        $(
+            impl<$($generics)*> ByteBufferWrite for $type {
+                write_sequence_method!(write_to_bytey_buffer);
+                write_sequence_method!(write_to_bytey_buffer_le);
+                write_sequence_method!(write_to_bytey_buffer_be);
+            }
+
+            impl_write_ref!([$($generics)*] $type);
+        )*
+    };
+}
+
+macro_rules! write_sequence_method {
+    ($method:ident) => {
+        #[inline]
+        fn $method<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+            Length(self.len()).$method(buffer)?;
+
+            for e in self.iter() {
+                e.$method(buffer)?;
+            }
+
+            Ok(())
+        }
+    };
+}
+
+/// Implements [`ByteBufferWrite`] for a set and a reference to it, written as the [`Length`] followed by every
+/// element in iteration order, or sorted by their encoded bytes if the sink is [`canonical`](Sink::canonical).
+macro_rules! impl_write_set {
+    ($([$($generics:tt)*] $type:ty;)*) => {
+        $(
+            impl<$($generics)*> ByteBufferWrite for $type {
+                write_set_method!(write_to_bytey_buffer);
+                write_set_method!(write_to_bytey_buffer_le);
+                write_set_method!(write_to_bytey_buffer_be);
+            }
+
+            impl_write_ref!([$($generics)*] $type);
+        )*
+    };
+}
+
+macro_rules! write_set_method {
+    ($method:ident) => {
+        #[inline]
+        fn $method<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+            Length(self.len()).$method(buffer)?;
+
+            if buffer.canonical() {
+                return write_sorted(
+                    buffer,
+                    self.iter(),
+                    |e, scratch| e.$method(scratch),
+                    |_, _| Ok(()),
+                );
+            }
+
+            for e in self.iter() {
+                e.$method(buffer)?;
+            }
+
+            Ok(())
+        }
+    };
+}
+
+/// Implements [`ByteBufferWrite`] for a map and a reference to it, written as the [`Length`] followed by the key
+/// and value of every entry in iteration order, or sorted by the encoded bytes of the keys if the sink is
+/// [`canonical`](Sink::canonical).
+macro_rules! impl_write_map {
+    ($([$($generics:tt)*] $type:ty;)*) => {
+        $(
+            impl<$($generics)*> ByteBufferWrite for $type {
+                write_map_method!(write_to_bytey_buffer);
+                write_map_method!(write_to_bytey_buffer_le);
+                write_map_method!(write_to_bytey_buffer_be);
+            }
+
+            impl_write_ref!([$($generics)*] $type);
+        )*
+    };
+}
+
+macro_rules! write_map_method {
+    ($method:ident) => {
+        #[inline]
+        fn $method<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+            Length(self.len()).$method(buffer)?;
+
+            if buffer.canonical() {
+                return write_sorted(
+                    buffer,
+                    self.iter(),
+                    |(k, _), scratch| k.$method(scratch),
+                    |(_, v), scratch| v.$method(scratch),
+                );
+            }
+
+            for (k, v) in self.iter() {
+                k.$method(buffer)?;
+                v.$method(buffer)?;
+            }
+
+            Ok(())
+        }
+    };
+}
+
+impl_write_sequence! {
+    [T: ByteBufferWrite] VecDeque<T>;
+    [T: ByteBufferWrite] LinkedList<T>;
+}
+
+impl_write_set! {
+    [T: ByteBufferWrite] BTreeSet<T>;
+    [T: ByteBufferWrite] BinaryHeap<T>;
+}
+
+impl_write_map! {
+    [K: ByteBufferWrite, V: ByteBufferWrite] BTreeMap<K, V>;
+}
+
+#[cfg(feature = "std")]
+impl_write_set! {
+    [T: ByteBufferWrite, H] HashSet<T, H>;
+}
+
+#[cfg(feature = "std")]
+impl_write_map! {
+    [K: ByteBufferWrite, V: ByteBufferWrite, H] HashMap<K, V, H>;
+}
@@ -0,0 +1,65 @@
+use crate::{
+    byte_buffer_write::ByteBufferWrite,
+    error::Result,
+    length_prefix::{Length, LengthPrefix},
+    stream::Sink,
+    varint::VarU64,
+};
+
+impl ByteBufferWrite for Length {
+    #[inline]
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        let prefix = buffer.length_prefix();
+
+        match prefix {
+            LengthPrefix::U8 => self.fit::<u8>(prefix)?.write_to_bytey_buffer(buffer),
+            LengthPrefix::U16 => self.fit::<u16>(prefix)?.write_to_bytey_buffer(buffer),
+            LengthPrefix::U32 => self.fit::<u32>(prefix)?.write_to_bytey_buffer(buffer),
+            LengthPrefix::U64 => self.fit::<u64>(prefix)?.write_to_bytey_buffer(buffer),
+            LengthPrefix::VarInt => VarU64(self.fit::<u64>(prefix)?).write_to_bytey_buffer(buffer),
+        }
+    }
+
+    #[inline]
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        let prefix = buffer.length_prefix();
+
+        match prefix {
+            LengthPrefix::U8 => self.fit::<u8>(prefix)?.write_to_bytey_buffer_le(buffer),
+            LengthPrefix::U16 => self.fit::<u16>(prefix)?.write_to_bytey_buffer_le(buffer),
+            LengthPrefix::U32 => self.fit::<u32>(prefix)?.write_to_bytey_buffer_le(buffer),
+            LengthPrefix::U64 => self.fit::<u64>(prefix)?.write_to_bytey_buffer_le(buffer),
+            LengthPrefix::VarInt => VarU64(self.fit::<u64>(prefix)?).write_to_bytey_buffer(buffer),
+        }
+    }
+
+    #[inline]
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        let prefix = buffer.length_prefix();
+
+        match prefix {
+            LengthPrefix::U8 => self.fit::<u8>(prefix)?.write_to_bytey_buffer_be(buffer),
+            LengthPrefix::U16 => self.fit::<u16>(prefix)?.write_to_bytey_buffer_be(buffer),
+            LengthPrefix::U32 => self.fit::<u32>(prefix)?.write_to_bytey_buffer_be(buffer),
+            LengthPrefix::U64 => self.fit::<u64>(prefix)?.write_to_bytey_buffer_be(buffer),
+            LengthPrefix::VarInt => VarU64(self.fit::<u64>(prefix)?).write_to_bytey_buffer(buffer),
+        }
+    }
+}
+
+impl ByteBufferWrite for &Length {
+    #[inline]
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        (*self).write_to_bytey_buffer(buffer)
+    }
+
+    #[inline]
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        (*self).write_to_bytey_buffer_le(buffer)
+    }
+
+    #[inline]
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        (*self).write_to_bytey_buffer_be(buffer)
+    }
+}
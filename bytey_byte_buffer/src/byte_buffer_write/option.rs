@@ -1,8 +1,8 @@
-use crate::{byte_buffer::ByteBuffer, byte_buffer_write::ByteBufferWrite, error::Result};
+use crate::{byte_buffer_write::ByteBufferWrite, error::Result, stream::Sink};
 
 impl<T: ByteBufferWrite> ByteBufferWrite for Option<T> {
     #[inline]
-    fn write_to_bytey_buffer(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         match self {
             Some(v) => {
                 1u8.write_to_bytey_buffer(buffer)?;
@@ -13,7 +13,7 @@ impl<T: ByteBufferWrite> ByteBufferWrite for Option<T> {
     }
 
     #[inline]
-    fn write_to_bytey_buffer_le(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         match self {
             Some(v) => {
                 1u8.write_to_bytey_buffer_le(buffer)?;
@@ -24,7 +24,7 @@ impl<T: ByteBufferWrite> ByteBufferWrite for Option<T> {
     }
 
     #[inline]
-    fn write_to_bytey_buffer_be(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         match self {
             Some(v) => {
                 1u8.write_to_bytey_buffer_be(buffer)?;
@@ -37,7 +37,7 @@ impl<T: ByteBufferWrite> ByteBufferWrite for Option<T> {
 
 impl<T: ByteBufferWrite> ByteBufferWrite for &Option<T> {
     #[inline]
-    fn write_to_bytey_buffer(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         match *self {
             Some(v) => {
                 1u8.write_to_bytey_buffer(buffer)?;
@@ -48,7 +48,7 @@ impl<T: ByteBufferWrite> ByteBufferWrite for &Option<T> {
     }
 
     #[inline]
-    fn write_to_bytey_buffer_le(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         match *self {
             Some(v) => {
                 1u8.write_to_bytey_buffer_le(buffer)?;
@@ -59,7 +59,7 @@ impl<T: ByteBufferWrite> ByteBufferWrite for &Option<T> {
     }
 
     #[inline]
-    fn write_to_bytey_buffer_be(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         match *self {
             Some(v) => {
                 1u8.write_to_bytey_buffer_be(buffer)?;
@@ -1,19 +1,19 @@
-use crate::{byte_buffer::ByteBuffer, byte_buffer_write::ByteBufferWrite, error::Result};
-use std::marker::PhantomData;
+use crate::{byte_buffer_write::ByteBufferWrite, error::Result, stream::Sink};
+use core::marker::PhantomData;
 
 impl<T> ByteBufferWrite for PhantomData<T> {
     #[inline]
-    fn write_to_bytey_buffer(&self, _buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer<S: Sink>(&self, _buffer: &mut S) -> Result<()> {
         Ok(())
     }
 
     #[inline]
-    fn write_to_bytey_buffer_le(&self, _buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_le<S: Sink>(&self, _buffer: &mut S) -> Result<()> {
         Ok(())
     }
 
     #[inline]
-    fn write_to_bytey_buffer_be(&self, _buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_be<S: Sink>(&self, _buffer: &mut S) -> Result<()> {
         Ok(())
     }
 }
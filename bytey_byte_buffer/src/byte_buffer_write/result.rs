@@ -1,8 +1,8 @@
-use crate::{byte_buffer::ByteBuffer, byte_buffer_write::ByteBufferWrite, error::Result};
+use crate::{byte_buffer_write::ByteBufferWrite, error::Result, stream::Sink};
 
-impl<T: ByteBufferWrite, E: ByteBufferWrite> ByteBufferWrite for std::result::Result<T, E> {
+impl<T: ByteBufferWrite, E: ByteBufferWrite> ByteBufferWrite for core::result::Result<T, E> {
     #[inline]
-    fn write_to_bytey_buffer(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         match self {
             Ok(v) => {
                 1u8.write_to_bytey_buffer(buffer)?;
@@ -16,7 +16,7 @@ impl<T: ByteBufferWrite, E: ByteBufferWrite> ByteBufferWrite for std::result::Re
     }
 
     #[inline]
-    fn write_to_bytey_buffer_le(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         match self {
             Ok(v) => {
                 1u8.write_to_bytey_buffer_le(buffer)?;
@@ -30,7 +30,7 @@ impl<T: ByteBufferWrite, E: ByteBufferWrite> ByteBufferWrite for std::result::Re
     }
 
     #[inline]
-    fn write_to_bytey_buffer_be(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         match self {
             Ok(v) => {
                 1u8.write_to_bytey_buffer_be(buffer)?;
@@ -44,9 +44,9 @@ impl<T: ByteBufferWrite, E: ByteBufferWrite> ByteBufferWrite for std::result::Re
     }
 }
 
-impl<T: ByteBufferWrite, E: ByteBufferWrite> ByteBufferWrite for &std::result::Result<T, E> {
+impl<T: ByteBufferWrite, E: ByteBufferWrite> ByteBufferWrite for &core::result::Result<T, E> {
     #[inline]
-    fn write_to_bytey_buffer(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         match *self {
             Ok(v) => {
                 1u8.write_to_bytey_buffer(buffer)?;
@@ -60,7 +60,7 @@ impl<T: ByteBufferWrite, E: ByteBufferWrite> ByteBufferWrite for &std::result::R
     }
 
     #[inline]
-    fn write_to_bytey_buffer_le(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         match *self {
             Ok(v) => {
                 1u8.write_to_bytey_buffer_le(buffer)?;
@@ -74,7 +74,7 @@ impl<T: ByteBufferWrite, E: ByteBufferWrite> ByteBufferWrite for &std::result::R
     }
 
     #[inline]
-    fn write_to_bytey_buffer_be(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         match *self {
             Ok(v) => {
                 1u8.write_to_bytey_buffer_be(buffer)?;
@@ -88,9 +88,9 @@ impl<T: ByteBufferWrite, E: ByteBufferWrite> ByteBufferWrite for &std::result::R
     }
 }
 
-impl<E: ByteBufferWrite> ByteBufferWrite for std::result::Result<(), E> {
+impl<E: ByteBufferWrite> ByteBufferWrite for core::result::Result<(), E> {
     #[inline]
-    fn write_to_bytey_buffer(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         match self {
             Ok(()) => 1u8.write_to_bytey_buffer(buffer),
             Err(e) => {
@@ -101,7 +101,7 @@ impl<E: ByteBufferWrite> ByteBufferWrite for std::result::Result<(), E> {
     }
 
     #[inline]
-    fn write_to_bytey_buffer_le(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         match self {
             Ok(()) => 1u8.write_to_bytey_buffer_le(buffer),
             Err(e) => {
@@ -112,7 +112,7 @@ impl<E: ByteBufferWrite> ByteBufferWrite for std::result::Result<(), E> {
     }
 
     #[inline]
-    fn write_to_bytey_buffer_be(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         match self {
             Ok(()) => 1u8.write_to_bytey_buffer_be(buffer),
             Err(e) => {
@@ -123,9 +123,9 @@ impl<E: ByteBufferWrite> ByteBufferWrite for std::result::Result<(), E> {
     }
 }
 
-impl<E: ByteBufferWrite> ByteBufferWrite for &std::result::Result<(), E> {
+impl<E: ByteBufferWrite> ByteBufferWrite for &core::result::Result<(), E> {
     #[inline]
-    fn write_to_bytey_buffer(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         match *self {
             Ok(()) => 1u8.write_to_bytey_buffer(buffer),
             Err(e) => {
@@ -136,7 +136,7 @@ impl<E: ByteBufferWrite> ByteBufferWrite for &std::result::Result<(), E> {
     }
 
     #[inline]
-    fn write_to_bytey_buffer_le(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         match *self {
             Ok(()) => 1u8.write_to_bytey_buffer_le(buffer),
             Err(e) => {
@@ -147,7 +147,7 @@ impl<E: ByteBufferWrite> ByteBufferWrite for &std::result::Result<(), E> {
     }
 
     #[inline]
-    fn write_to_bytey_buffer_be(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         match *self {
             Ok(()) => 1u8.write_to_bytey_buffer_be(buffer),
             Err(e) => {
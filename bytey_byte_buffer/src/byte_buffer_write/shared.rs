@@ -0,0 +1,30 @@
+use crate::{byte_buffer_write::ByteBufferWrite, error::Result, stream::Sink};
+use alloc::rc::Rc;
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+
+/// Implements [`ByteBufferWrite`] for a shared pointer, written like the value it points to.
+macro_rules! impl_write_shared {
+    ($pointer:ident) => {
+        impl<T: ByteBufferWrite + ?Sized> ByteBufferWrite for $pointer<T> {
+            #[inline]
+            fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+                self.as_ref().write_to_bytey_buffer(buffer)
+            }
+
+            #[inline]
+            fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+                self.as_ref().write_to_bytey_buffer_le(buffer)
+            }
+
+            #[inline]
+            fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+                self.as_ref().write_to_bytey_buffer_be(buffer)
+            }
+        }
+    };
+}
+
+impl_write_shared!(Rc);
+#[cfg(target_has_atomic = "ptr")]
+impl_write_shared!(Arc);
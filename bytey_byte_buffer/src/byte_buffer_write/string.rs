@@ -1,11 +1,17 @@
-use crate::{byte_buffer::ByteBuffer, byte_buffer_write::ByteBufferWrite, error::Result};
+use crate::{
+    byte_buffer_write::ByteBufferWrite,
+    error::Result,
+    length_prefix::Length,
+    stream::Sink,
+};
+use alloc::string::String;
 
 impl ByteBufferWrite for str {
     #[inline]
-    fn write_to_bytey_buffer(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         let bytestr = self.as_bytes();
         let len = bytestr.len();
-        len.write_to_bytey_buffer(buffer)?;
+        Length(len).write_to_bytey_buffer(buffer)?;
 
         if len > 0 {
             buffer.write_slice(bytestr)?;
@@ -15,10 +21,10 @@ impl ByteBufferWrite for str {
     }
 
     #[inline]
-    fn write_to_bytey_buffer_le(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         let bytestr = self.as_bytes();
         let len = bytestr.len();
-        len.write_to_bytey_buffer_le(buffer)?;
+        Length(len).write_to_bytey_buffer_le(buffer)?;
 
         if len > 0 {
             buffer.write_slice(bytestr)?;
@@ -28,10 +34,10 @@ impl ByteBufferWrite for str {
     }
 
     #[inline]
-    fn write_to_bytey_buffer_be(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         let bytestr = self.as_bytes();
         let len = bytestr.len();
-        len.write_to_bytey_buffer_be(buffer)?;
+        Length(len).write_to_bytey_buffer_be(buffer)?;
 
         if len > 0 {
             buffer.write_slice(bytestr)?;
@@ -43,10 +49,10 @@ impl ByteBufferWrite for str {
 
 impl ByteBufferWrite for &'_ str {
     #[inline]
-    fn write_to_bytey_buffer(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         let bytestr = self.as_bytes();
         let len = bytestr.len();
-        len.write_to_bytey_buffer(buffer)?;
+        Length(len).write_to_bytey_buffer(buffer)?;
 
         if len > 0 {
             buffer.write_slice(bytestr)?;
@@ -56,10 +62,10 @@ impl ByteBufferWrite for &'_ str {
     }
 
     #[inline]
-    fn write_to_bytey_buffer_le(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         let bytestr = self.as_bytes();
         let len = bytestr.len();
-        len.write_to_bytey_buffer_le(buffer)?;
+        Length(len).write_to_bytey_buffer_le(buffer)?;
 
         if len > 0 {
             buffer.write_slice(bytestr)?;
@@ -69,10 +75,10 @@ impl ByteBufferWrite for &'_ str {
     }
 
     #[inline]
-    fn write_to_bytey_buffer_be(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         let bytestr = self.as_bytes();
         let len = bytestr.len();
-        len.write_to_bytey_buffer_be(buffer)?;
+        Length(len).write_to_bytey_buffer_be(buffer)?;
 
         if len > 0 {
             buffer.write_slice(bytestr)?;
@@ -84,10 +90,10 @@ impl ByteBufferWrite for &'_ str {
 
 impl ByteBufferWrite for String {
     #[inline]
-    fn write_to_bytey_buffer(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         let bytestr = self.as_bytes();
         let len = bytestr.len();
-        len.write_to_bytey_buffer(buffer)?;
+        Length(len).write_to_bytey_buffer(buffer)?;
 
         if len > 0 {
             buffer.write_slice(bytestr)?;
@@ -97,10 +103,10 @@ impl ByteBufferWrite for String {
     }
 
     #[inline]
-    fn write_to_bytey_buffer_le(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         let bytestr = self.as_bytes();
         let len = bytestr.len();
-        len.write_to_bytey_buffer_le(buffer)?;
+        Length(len).write_to_bytey_buffer_le(buffer)?;
 
         if len > 0 {
             buffer.write_slice(bytestr)?;
@@ -110,10 +116,10 @@ impl ByteBufferWrite for String {
     }
 
     #[inline]
-    fn write_to_bytey_buffer_be(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         let bytestr = self.as_bytes();
         let len = bytestr.len();
-        len.write_to_bytey_buffer_be(buffer)?;
+        Length(len).write_to_bytey_buffer_be(buffer)?;
 
         if len > 0 {
             buffer.write_slice(bytestr)?;
@@ -125,10 +131,10 @@ impl ByteBufferWrite for String {
 
 impl ByteBufferWrite for &String {
     #[inline]
-    fn write_to_bytey_buffer(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         let bytestr = self.as_bytes();
         let len = bytestr.len();
-        len.write_to_bytey_buffer(buffer)?;
+        Length(len).write_to_bytey_buffer(buffer)?;
 
         if len > 0 {
             buffer.write_slice(bytestr)?;
@@ -138,10 +144,10 @@ impl ByteBufferWrite for &String {
     }
 
     #[inline]
-    fn write_to_bytey_buffer_le(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         let bytestr = self.as_bytes();
         let len = bytestr.len();
-        len.write_to_bytey_buffer_le(buffer)?;
+        Length(len).write_to_bytey_buffer_le(buffer)?;
 
         if len > 0 {
             buffer.write_slice(bytestr)?;
@@ -151,10 +157,10 @@ impl ByteBufferWrite for &String {
     }
 
     #[inline]
-    fn write_to_bytey_buffer_be(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         let bytestr = self.as_bytes();
         let len = bytestr.len();
-        len.write_to_bytey_buffer_be(buffer)?;
+        Length(len).write_to_bytey_buffer_be(buffer)?;
 
         if len > 0 {
             buffer.write_slice(bytestr)?;
@@ -1,21 +1,21 @@
-use crate::{byte_buffer::ByteBuffer, byte_buffer_write::ByteBufferWrite, error::Result};
-use std::time::Duration;
+use crate::{byte_buffer_write::ByteBufferWrite, error::Result, stream::Sink};
+use core::time::Duration;
 
 impl ByteBufferWrite for Duration {
     #[inline]
-    fn write_to_bytey_buffer(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         self.as_secs().write_to_bytey_buffer(buffer)?;
         self.subsec_nanos().write_to_bytey_buffer(buffer)
     }
 
     #[inline]
-    fn write_to_bytey_buffer_le(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         self.as_secs().write_to_bytey_buffer_le(buffer)?;
         self.subsec_nanos().write_to_bytey_buffer_le(buffer)
     }
 
     #[inline]
-    fn write_to_bytey_buffer_be(&self, buffer: &mut ByteBuffer) -> Result<()> {
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
         self.as_secs().write_to_bytey_buffer_be(buffer)?;
         self.subsec_nanos().write_to_bytey_buffer_be(buffer)
     }
@@ -1,8 +1,9 @@
 use crate::{
-    byte_buffer::ByteBuffer,
     byte_buffer_write::ByteBufferWrite,
     error::{ByteBufferError, Result},
+    stream::Sink,
 };
+use alloc::format;
 
 macro_rules! tuple_impls {
     ($(
@@ -14,7 +15,7 @@ macro_rules! tuple_impls {
             impl<$($T: ByteBufferWrite),+> ByteBufferWrite for ($($T,)+)
             {
                 #[inline]
-                fn write_to_bytey_buffer(&self, buffer: &mut ByteBuffer) -> Result<()> {
+                fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
                     $(match self.$idx.write_to_bytey_buffer(buffer) {
                         Ok(_) => {}
                         Err(e) => return Err(ByteBufferError::OtherError {
@@ -25,7 +26,7 @@ macro_rules! tuple_impls {
                 }
 
                 #[inline]
-                fn write_to_bytey_buffer_le(&self, buffer: &mut ByteBuffer) -> Result<()> {
+                fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
                     $(match self.$idx.write_to_bytey_buffer_le(buffer) {
                         Ok(_) => {}
                         Err(e) => return Err(ByteBufferError::OtherError {
@@ -36,7 +37,7 @@ macro_rules! tuple_impls {
                 }
 
                 #[inline]
-                fn write_to_bytey_buffer_be(&self, buffer: &mut ByteBuffer) -> Result<()> {
+                fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
                     $(match self.$idx.write_to_bytey_buffer_be(buffer) {
                         Ok(_) => {}
                         Err(e) => return Err(ByteBufferError::OtherError {
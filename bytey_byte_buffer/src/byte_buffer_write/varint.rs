@@ -0,0 +1,142 @@
+use crate::{
+    byte_buffer_write::ByteBufferWrite,
+    error::Result,
+    stream::Sink,
+    varint::{self, VarI32, VarI64, VarU32, VarU64},
+};
+
+impl ByteBufferWrite for VarU32 {
+    #[inline]
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        varint::write_unsigned(buffer, u64::from(self.0))
+    }
+
+    #[inline]
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        self.write_to_bytey_buffer(buffer)
+    }
+
+    #[inline]
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        self.write_to_bytey_buffer(buffer)
+    }
+}
+
+impl ByteBufferWrite for &VarU32 {
+    #[inline]
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        varint::write_unsigned(buffer, u64::from(self.0))
+    }
+
+    #[inline]
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        self.write_to_bytey_buffer(buffer)
+    }
+
+    #[inline]
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        self.write_to_bytey_buffer(buffer)
+    }
+}
+
+impl ByteBufferWrite for VarU64 {
+    #[inline]
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        varint::write_unsigned(buffer, self.0)
+    }
+
+    #[inline]
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        self.write_to_bytey_buffer(buffer)
+    }
+
+    #[inline]
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        self.write_to_bytey_buffer(buffer)
+    }
+}
+
+impl ByteBufferWrite for &VarU64 {
+    #[inline]
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        varint::write_unsigned(buffer, self.0)
+    }
+
+    #[inline]
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        self.write_to_bytey_buffer(buffer)
+    }
+
+    #[inline]
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        self.write_to_bytey_buffer(buffer)
+    }
+}
+
+impl ByteBufferWrite for VarI32 {
+    #[inline]
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        varint::write_unsigned(buffer, varint::zigzag_encode(self.0.into()))
+    }
+
+    #[inline]
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        self.write_to_bytey_buffer(buffer)
+    }
+
+    #[inline]
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        self.write_to_bytey_buffer(buffer)
+    }
+}
+
+impl ByteBufferWrite for &VarI32 {
+    #[inline]
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        varint::write_unsigned(buffer, varint::zigzag_encode(self.0.into()))
+    }
+
+    #[inline]
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        self.write_to_bytey_buffer(buffer)
+    }
+
+    #[inline]
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        self.write_to_bytey_buffer(buffer)
+    }
+}
+
+impl ByteBufferWrite for VarI64 {
+    #[inline]
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        varint::write_unsigned(buffer, varint::zigzag_encode(self.0))
+    }
+
+    #[inline]
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        self.write_to_bytey_buffer(buffer)
+    }
+
+    #[inline]
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        self.write_to_bytey_buffer(buffer)
+    }
+}
+
+impl ByteBufferWrite for &VarI64 {
+    #[inline]
+    fn write_to_bytey_buffer<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        varint::write_unsigned(buffer, varint::zigzag_encode(self.0))
+    }
+
+    #[inline]
+    fn write_to_bytey_buffer_le<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        self.write_to_bytey_buffer(buffer)
+    }
+
+    #[inline]
+    fn write_to_bytey_buffer_be<S: Sink>(&self, buffer: &mut S) -> Result<()> {
+        self.write_to_bytey_buffer(buffer)
+    }
+}
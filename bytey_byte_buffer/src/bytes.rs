@@ -0,0 +1,307 @@
+//! Interoperability with the [`bytes`](::bytes) crate.
+//!
+//! Requires the `bytes` feature.
+//!
+//! [`ByteBuffer`] implements [`Buf`] and [`BufMut`], reading and writing at the cursor like
+//! [`read_slice`](ByteBuffer::read_slice) and [`write_slice`](ByteBuffer::write_slice) do.
+//! [`BufSource`] and [`BufSink`] read and write values straight from any [`Buf`] or to any [`BufMut`].
+//!
+//! # Examples
+//! ```
+//! use bytes::{BufMut, Bytes, BytesMut};
+//! use bytey_byte_buffer::{byte_buffer::ByteBuffer, bytes::BufSource, stream::Source};
+//!
+//! let mut bytes = BytesMut::new();
+//! bytes.put_u16(7);
+//!
+//! let mut buffer = ByteBuffer::from_bytes_mut(bytes);
+//! assert_eq!(buffer.read_be::<u16>().unwrap(), 7);
+//!
+//! buffer.write_be(8u16).unwrap();
+//!
+//! let mut source = BufSource::new(buffer.into_bytes());
+//! assert_eq!(source.read_be::<(u16, u16)>().unwrap(), (7, 8));
+//! ```
+
+use crate::{
+    byte_buffer::ByteBuffer,
+    decode_limits::DecodeLimits,
+    error::{ByteBufferError, Result},
+    length_prefix::LengthPrefix,
+    stream::{Sink, Source},
+};
+use ::bytes::{Buf, BufMut, Bytes, BytesMut, buf::UninitSlice};
+use alloc::vec::Vec;
+
+impl ByteBuffer {
+    /// Converts the [`ByteBuffer`] into [`Bytes`] holding the bytes up to the length, without copying.
+    pub fn into_bytes(self) -> Bytes {
+        Bytes::from(self.into_vec())
+    }
+
+    /// Constructs a [`ByteBuffer`] from [`BytesMut`] with the cursor at position 0.
+    ///
+    /// The allocation is reused if the [`BytesMut`] is its only owner and starts at the start of it,
+    /// otherwise the bytes are copied.
+    pub fn from_bytes_mut(bytes: BytesMut) -> Self {
+        Self::from_vec(Vec::from(bytes))
+    }
+}
+
+impl From<ByteBuffer> for Bytes {
+    fn from(buffer: ByteBuffer) -> Self {
+        buffer.into_bytes()
+    }
+}
+
+impl From<BytesMut> for ByteBuffer {
+    fn from(bytes: BytesMut) -> Self {
+        ByteBuffer::from_bytes_mut(bytes)
+    }
+}
+
+impl From<Bytes> for ByteBuffer {
+    /// The allocation is reused if the [`Bytes`] is its only owner, otherwise the bytes are copied.
+    fn from(bytes: Bytes) -> Self {
+        ByteBuffer::from_vec(Vec::from(bytes))
+    }
+}
+
+impl Buf for ByteBuffer {
+    fn remaining(&self) -> usize {
+        self.length() - self.cursor()
+    }
+
+    fn chunk(&self) -> &[u8] {
+        ByteBuffer::remaining(self)
+    }
+
+    fn advance(&mut self, cnt: usize) {
+        let cursor = self.cursor() + cnt;
+
+        if let Err(error) = self.move_cursor(cursor) {
+            panic!("{error}");
+        }
+    }
+}
+
+unsafe impl BufMut for ByteBuffer {
+    fn remaining_mut(&self) -> usize {
+        ByteBuffer::MAX_SIZE - self.cursor()
+    }
+
+    unsafe fn advance_mut(&mut self, cnt: usize) {
+        let cursor = self.cursor() + cnt;
+
+        assert!(
+            cursor <= self.capacity(),
+            "Cannot advance past the capacity: {cursor} > {}",
+            self.capacity()
+        );
+
+        if cursor > self.length() {
+            // SAFETY: the caller initialized the bytes up to the new cursor.
+            unsafe {
+                self.set_length(cursor);
+            }
+        }
+
+        self.move_cursor(cursor)
+            .expect("the cursor is within the length");
+    }
+
+    fn chunk_mut(&mut self) -> &mut UninitSlice {
+        if self.cursor() == self.capacity() {
+            let capacity = grown_capacity(self.capacity());
+
+            if let Err(error) = self.resize(capacity) {
+                panic!("{error}");
+            }
+        }
+
+        UninitSlice::uninit(self.spare_mut())
+    }
+}
+
+/// Doubles the capacity like [`write_slice`](ByteBuffer::write_slice) does when it has to grow.
+fn grown_capacity(capacity: usize) -> usize {
+    (capacity + 1)
+        .checked_next_power_of_two()
+        .unwrap_or(ByteBuffer::MAX_SIZE)
+        .min(ByteBuffer::MAX_SIZE)
+}
+
+/// A [`Source`] reading from a [`Buf`].
+///
+/// Values that fit in the current [`chunk`](Buf::chunk) are read without copying, values spanning multiple
+/// chunks are copied into a scratch buffer first.
+#[derive(Debug)]
+pub struct BufSource<B: Buf> {
+    buf: B,
+    scratch: Vec<u8>,
+    // Bytes of the current chunk that were returned by the last read_slice and still have to be advanced.
+    pending: usize,
+    offset: usize,
+    length_prefix: LengthPrefix,
+    canonical: bool,
+    decode_limits: DecodeLimits,
+}
+
+impl<B: Buf> BufSource<B> {
+    /// Constructs a new [`BufSource`] reading from the given [`Buf`] with the default settings.
+    pub fn new(buf: B) -> Self {
+        Self {
+            buf,
+            scratch: Vec::new(),
+            pending: 0,
+            offset: 0,
+            length_prefix: LengthPrefix::default(),
+            canonical: false,
+            decode_limits: DecodeLimits::new(),
+        }
+    }
+
+    /// Sets the [`LengthPrefix`] used to read the length of strings and collections.
+    pub fn set_length_prefix(&mut self, length_prefix: LengthPrefix) -> &mut Self {
+        self.length_prefix = length_prefix;
+
+        self
+    }
+
+    /// Sets whether maps and sets with duplicate keys are rejected, see [`ByteBuffer::set_canonical`].
+    pub fn set_canonical(&mut self, canonical: bool) -> &mut Self {
+        self.canonical = canonical;
+
+        self
+    }
+
+    /// Sets the [`DecodeLimits`] enforced while reading, see [`ByteBuffer::set_decode_limits`].
+    pub fn set_decode_limits(&mut self, decode_limits: DecodeLimits) -> &mut Self {
+        self.decode_limits = decode_limits.reset();
+
+        self
+    }
+
+    /// Returns the [`Buf`], advanced past every value that has been read.
+    pub fn into_inner(mut self) -> B {
+        self.buf.advance(self.pending);
+        self.buf
+    }
+}
+
+impl<B: Buf> Source for BufSource<B> {
+    fn read_slice(&mut self, size: usize) -> Result<&[u8]> {
+        self.buf.advance(self.pending);
+        self.pending = 0;
+
+        let remaining = self.buf.remaining();
+
+        if size > remaining {
+            return Err(ByteBufferError::ReadOutOfBounds {
+                length: remaining,
+                start: 0,
+                end: size,
+            });
+        }
+
+        self.offset += size;
+
+        if self.buf.chunk().len() >= size {
+            self.pending = size;
+
+            return Ok(&self.buf.chunk()[..size]);
+        }
+
+        self.scratch.clear();
+        self.scratch.resize(size, 0);
+        self.buf.copy_to_slice(&mut self.scratch);
+
+        Ok(&self.scratch)
+    }
+
+    fn length_prefix(&self) -> LengthPrefix {
+        self.length_prefix
+    }
+
+    fn canonical(&self) -> bool {
+        self.canonical
+    }
+
+    fn offset(&self) -> Option<usize> {
+        Some(self.offset)
+    }
+
+    fn decode_limits_mut(&mut self) -> Option<&mut DecodeLimits> {
+        Some(&mut self.decode_limits)
+    }
+}
+
+/// A [`Sink`] writing to a [`BufMut`].
+#[derive(Debug)]
+pub struct BufSink<B: BufMut> {
+    buf: B,
+    length_prefix: LengthPrefix,
+    canonical: bool,
+}
+
+impl<B: BufMut> BufSink<B> {
+    /// Constructs a new [`BufSink`] writing to the given [`BufMut`] with the default settings.
+    pub fn new(buf: B) -> Self {
+        Self {
+            buf,
+            length_prefix: LengthPrefix::default(),
+            canonical: false,
+        }
+    }
+
+    /// Sets the [`LengthPrefix`] used to write the length of strings and collections.
+    pub fn set_length_prefix(&mut self, length_prefix: LengthPrefix) -> &mut Self {
+        self.length_prefix = length_prefix;
+
+        self
+    }
+
+    /// Sets whether maps and sets are written in canonical order, see [`ByteBuffer::set_canonical`].
+    pub fn set_canonical(&mut self, canonical: bool) -> &mut Self {
+        self.canonical = canonical;
+
+        self
+    }
+
+    /// Returns a reference to the underlying [`BufMut`].
+    pub fn get_ref(&self) -> &B {
+        &self.buf
+    }
+
+    /// Returns a mutable reference to the underlying [`BufMut`].
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.buf
+    }
+
+    /// Returns the underlying [`BufMut`].
+    pub fn into_inner(self) -> B {
+        self.buf
+    }
+}
+
+impl<B: BufMut> Sink for BufSink<B> {
+    /// # Errors
+    /// - [`ByteBufferError::MaxCapacity`] is returned if the [`BufMut`] has no room for the bytes.
+    fn write_slice(&mut self, source: &[u8]) -> Result<()> {
+        if self.buf.remaining_mut() < source.len() {
+            return Err(ByteBufferError::MaxCapacity);
+        }
+
+        self.buf.put_slice(source);
+
+        Ok(())
+    }
+
+    fn length_prefix(&self) -> LengthPrefix {
+        self.length_prefix
+    }
+
+    fn canonical(&self) -> bool {
+        self.canonical
+    }
+}
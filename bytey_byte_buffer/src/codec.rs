@@ -0,0 +1,257 @@
+//! A [`tokio_util::codec`] implementation for types implementing [`ByteBufferWrite`] and [`ByteBufferRead`].
+//!
+//! Requires the `tokio` feature.
+
+use crate::{
+    byte_buffer_read::ByteBufferRead,
+    byte_buffer_ref::ByteBufferRef,
+    byte_buffer_write::ByteBufferWrite,
+    bytes::BufSink,
+    decode_limits::DecodeLimits,
+    error::{ByteBufferError, Result},
+    fixed_buffer::ArrayByteBuffer,
+    frame_reader::{self, DEFAULT_MAX_FRAME_LENGTH},
+    length_prefix::{Length, LengthPrefix},
+    stream::Sink,
+};
+use bytes::{Buf, BufMut, BytesMut};
+use core::marker::PhantomData;
+use tokio_util::codec::{Decoder, Encoder};
+
+/// Frames values of type T with a length prefix, so they can be sent over a stream with
+/// [`Framed`](tokio_util::codec::Framed).
+///
+/// Every frame is the length of the value followed by the value itself, both written in **big endian** ordering.
+/// The length is stored as a [`LengthPrefix::U32`] unless changed with [`set_length_prefix`](Self::set_length_prefix),
+/// the frames can also be read with a [`FrameReader`](crate::frame_reader::FrameReader).
+/// The value is written straight into the destination and read in place from the frame, with the default
+/// [`LengthPrefix`] for strings and collections and the [`DecodeLimits`] set with
+/// [`set_decode_limits`](Self::set_decode_limits). Bytes left in a frame after the value return
+/// [`ByteBufferError::TrailingBytes`].
+///
+/// # Examples
+/// ```
+/// use bytes::BytesMut;
+/// use bytey_byte_buffer::codec::ByteyCodec;
+/// use tokio_util::codec::{Decoder, Encoder};
+///
+/// let mut codec = ByteyCodec::<u32>::new();
+/// let mut bytes = BytesMut::new();
+///
+/// codec.encode(7, &mut bytes).unwrap();
+/// assert_eq!(&bytes[..], [0, 0, 0, 4, 0, 0, 0, 7]);
+///
+/// let mut partial = bytes.split_to(6);
+/// assert_eq!(codec.decode(&mut partial).unwrap(), None);
+///
+/// partial.unsplit(bytes);
+/// assert_eq!(codec.decode(&mut partial).unwrap(), Some(7));
+/// ```
+#[derive(Debug)]
+pub struct ByteyCodec<T> {
+    length_prefix: LengthPrefix,
+    max_frame_length: usize,
+    decode_limits: DecodeLimits,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> ByteyCodec<T> {
+    pub fn new() -> Self {
+        Self {
+            length_prefix: LengthPrefix::U32,
+            max_frame_length: DEFAULT_MAX_FRAME_LENGTH,
+            decode_limits: DecodeLimits::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Constructs a new [`ByteyCodec`] reading values with the given [`DecodeLimits`].
+    pub fn with_decode_limits(decode_limits: DecodeLimits) -> Self {
+        let mut codec = Self::new();
+
+        codec.set_decode_limits(decode_limits);
+        codec
+    }
+
+    /// Returns the [`LengthPrefix`] used for the length of a frame.
+    pub fn length_prefix(&self) -> LengthPrefix {
+        self.length_prefix
+    }
+
+    /// Sets the [`LengthPrefix`] used for the length of a frame, both sides have to use the same prefix.
+    pub fn set_length_prefix(&mut self, length_prefix: LengthPrefix) -> &mut Self {
+        self.length_prefix = length_prefix;
+
+        self
+    }
+
+    /// Returns the largest length of a frame, without the length prefix.
+    pub fn max_frame_length(&self) -> usize {
+        self.max_frame_length
+    }
+
+    /// Sets the largest length of a frame, without the length prefix.
+    ///
+    /// Larger frames return [`ByteBufferError::LengthOverflow`] instead of being buffered, so a peer can't make
+    /// the decoder allocate an arbitrary amount of memory.
+    pub fn set_max_frame_length(&mut self, max_frame_length: usize) -> &mut Self {
+        self.max_frame_length = max_frame_length;
+
+        self
+    }
+
+    /// Returns the [`DecodeLimits`] enforced while reading a value from a frame.
+    pub fn decode_limits(&self) -> DecodeLimits {
+        self.decode_limits
+    }
+
+    /// Sets the [`DecodeLimits`] enforced while reading a value from a frame, see
+    /// [`ByteBuffer::set_decode_limits`](crate::byte_buffer::ByteBuffer::set_decode_limits).
+    ///
+    /// The limits apply to every frame on its own.
+    pub fn set_decode_limits(&mut self, decode_limits: DecodeLimits) -> &mut Self {
+        self.decode_limits = decode_limits.reset();
+
+        self
+    }
+
+    fn check_length(&self, length: usize) -> Result<()> {
+        if length > self.max_frame_length {
+            return Err(ByteBufferError::LengthOverflow {
+                length: length as u64,
+                target: "the max frame length",
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Appends the frame to `dst`, nothing is appended if writing the value fails.
+    fn encode_frame<V: ByteBufferWrite>(&self, item: V, dst: &mut BytesMut) -> Result<()> {
+        let start = dst.len();
+        let result = self.write_frame(item, start, dst);
+
+        if result.is_err() {
+            dst.truncate(start);
+        }
+
+        result
+    }
+
+    fn write_frame<V: ByteBufferWrite>(
+        &self,
+        item: V,
+        start: usize,
+        dst: &mut BytesMut,
+    ) -> Result<()> {
+        // Fixed size prefixes are overwritten once the length of the value is known. A varint takes
+        // as many bytes as the length needs, so it is moved in front of the value instead.
+        let reserved = match self.length_prefix {
+            LengthPrefix::U8 => 1,
+            LengthPrefix::U16 => 2,
+            LengthPrefix::U32 => 4,
+            LengthPrefix::U64 => 8,
+            LengthPrefix::VarInt => 0,
+        };
+
+        dst.put_bytes(0, reserved);
+        BufSink::new(&mut *dst).write_be(item)?;
+
+        let length = dst.len() - start - reserved;
+
+        self.check_length(length)?;
+
+        let mut header = ArrayByteBuffer::<10>::new();
+
+        header.set_length_prefix(self.length_prefix);
+        header.write_be(Length(length))?;
+
+        let header = header.as_bytes();
+
+        if reserved == 0 {
+            dst.extend_from_slice(header);
+            dst[start..].rotate_right(header.len());
+        } else {
+            dst[start..start + reserved].copy_from_slice(header);
+        }
+
+        Ok(())
+    }
+
+    fn decode_frame(&self, frame: &[u8]) -> Result<T>
+    where
+        T: ByteBufferRead,
+    {
+        let mut reader = ByteBufferRef::new(frame);
+
+        reader.set_decode_limits(self.decode_limits);
+
+        let value = reader.read_be::<T>()?;
+
+        frame_reader::check_trailing(frame.len(), reader.cursor())?;
+
+        Ok(value)
+    }
+}
+
+impl<T> Default for ByteyCodec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for ByteyCodec<T> {
+    fn clone(&self) -> Self {
+        Self {
+            length_prefix: self.length_prefix,
+            max_frame_length: self.max_frame_length,
+            decode_limits: self.decode_limits,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: ByteBufferWrite> Encoder<T> for ByteyCodec<T> {
+    type Error = ByteBufferError;
+
+    fn encode(&mut self, item: T, dst: &mut BytesMut) -> Result<()> {
+        self.encode_frame(item, dst)
+    }
+}
+
+impl<'a, T> Encoder<&'a T> for ByteyCodec<T>
+where
+    &'a T: ByteBufferWrite,
+{
+    type Error = ByteBufferError;
+
+    fn encode(&mut self, item: &'a T, dst: &mut BytesMut) -> Result<()> {
+        self.encode_frame(item, dst)
+    }
+}
+
+impl<T: ByteBufferRead> Decoder for ByteyCodec<T> {
+    type Item = T;
+    type Error = ByteBufferError;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<T>> {
+        let (header, length) =
+            match frame_reader::frame_header(src, self.length_prefix, self.max_frame_length) {
+                Ok(frame) => frame,
+                Err(ByteBufferError::Incomplete { needed }) => {
+                    src.reserve(needed);
+
+                    return Ok(None);
+                }
+                Err(error) => return Err(error),
+            };
+
+        src.advance(header);
+
+        // The frame is dropped even if the value can't be read, so the next frame can still be decoded.
+        let value = self.decode_frame(&src[..length]);
+
+        src.advance(length);
+        value.map(Some)
+    }
+}
@@ -0,0 +1,216 @@
+//! Limits that bound the memory and nesting a value read from untrusted bytes can use.
+
+use crate::error::{ByteBufferError, Result};
+
+/// The most bytes a collection reserves up front, larger collections grow while their elements are read.
+///
+/// This stops a forged length from allocating more memory than the bytes that actually follow it, even
+/// without any [`DecodeLimits`] set.
+const MAX_PREALLOCATION: usize = 64 * 1024;
+
+/// Returns the capacity to reserve for a collection of `length` elements of type T.
+#[inline]
+pub(crate) fn preallocation<T>(length: usize) -> usize {
+    length.min(MAX_PREALLOCATION / core::mem::size_of::<T>().max(1))
+}
+
+/// The limits a reader enforces on every built-in and derived [`ByteBufferRead`](crate::byte_buffer_read::ByteBufferRead)
+/// impl, to reject malicious or corrupt data before it allocates.
+///
+/// The limits are a setting of the buffer that reads the data, see
+/// [`set_decode_limits`](crate::byte_buffer::ByteBuffer::set_decode_limits). Every limit is unlimited by default.
+///
+/// # Behaviour
+/// - The collection and string limits are checked against every length that is read.
+/// - The allocation limit counts the bytes allocated for collections, strings and boxes of one top level value,
+///   the count starts over for every value read from the buffer.
+/// - The depth limit counts the collections, boxes and derived types a value is nested in.
+///
+/// # Examples
+/// ```
+/// use bytey_byte_buffer::{byte_buffer::ByteBuffer, decode_limits::DecodeLimits, error::ByteBufferError};
+///
+/// let mut buffer = ByteBuffer::new().unwrap();
+/// let mut limits = DecodeLimits::new();
+///
+/// limits.set_max_collection_length(2);
+/// buffer.set_decode_limits(limits);
+///
+/// buffer.write(vec![1u8, 2, 3]).unwrap();
+/// buffer.move_cursor(0).unwrap();
+///
+/// assert_eq!(
+///     buffer.read::<Vec<u8>>(),
+///     Err(ByteBufferError::CollectionTooLong { length: 3, max: 2 })
+/// );
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct DecodeLimits {
+    max_collection_length: usize,
+    max_string_length: usize,
+    max_allocation: usize,
+    max_depth: usize,
+    allocated: usize,
+    depth: usize,
+}
+
+impl DecodeLimits {
+    /// Constructs new [`DecodeLimits`] without any limit.
+    pub const fn new() -> Self {
+        Self {
+            max_collection_length: usize::MAX,
+            max_string_length: usize::MAX,
+            max_allocation: usize::MAX,
+            max_depth: usize::MAX,
+            allocated: 0,
+            depth: 0,
+        }
+    }
+
+    /// Returns the largest amount of elements a collection can have.
+    pub fn max_collection_length(&self) -> usize {
+        self.max_collection_length
+    }
+
+    /// Sets the largest amount of elements a collection can have.
+    ///
+    /// Reading a longer collection returns [`ByteBufferError::CollectionTooLong`].
+    pub fn set_max_collection_length(&mut self, max_collection_length: usize) -> &mut Self {
+        self.max_collection_length = max_collection_length;
+
+        self
+    }
+
+    /// Returns the largest length of a string in bytes.
+    pub fn max_string_length(&self) -> usize {
+        self.max_string_length
+    }
+
+    /// Sets the largest length of a string in bytes.
+    ///
+    /// Reading a longer string returns [`ByteBufferError::StringTooLong`].
+    pub fn set_max_string_length(&mut self, max_string_length: usize) -> &mut Self {
+        self.max_string_length = max_string_length;
+
+        self
+    }
+
+    /// Returns the most bytes that can be allocated while reading a value.
+    pub fn max_allocation(&self) -> usize {
+        self.max_allocation
+    }
+
+    /// Sets the most bytes that can be allocated while reading a value.
+    ///
+    /// Reading a value that allocates more returns [`ByteBufferError::AllocationLimit`].
+    pub fn set_max_allocation(&mut self, max_allocation: usize) -> &mut Self {
+        self.max_allocation = max_allocation;
+
+        self
+    }
+
+    /// Returns the deepest a value can be nested.
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    /// Sets the deepest a value can be nested.
+    ///
+    /// Reading a value nested deeper returns [`ByteBufferError::DepthLimit`].
+    pub fn set_max_depth(&mut self, max_depth: usize) -> &mut Self {
+        self.max_depth = max_depth;
+
+        self
+    }
+
+    /// Returns the same limits with nothing counted yet.
+    pub(crate) fn reset(self) -> Self {
+        Self {
+            allocated: 0,
+            depth: 0,
+            ..self
+        }
+    }
+
+    pub(crate) fn check_collection<T>(&mut self, length: usize) -> Result<()> {
+        if length > self.max_collection_length {
+            return Err(ByteBufferError::CollectionTooLong {
+                length,
+                max: self.max_collection_length,
+            });
+        }
+
+        self.allocate(length.saturating_mul(core::mem::size_of::<T>()))
+    }
+
+    pub(crate) fn check_string(&mut self, length: usize) -> Result<()> {
+        if length > self.max_string_length {
+            return Err(ByteBufferError::StringTooLong {
+                length,
+                max: self.max_string_length,
+            });
+        }
+
+        self.allocate(length)
+    }
+
+    pub(crate) fn allocate(&mut self, size: usize) -> Result<()> {
+        // Outside of a nested value every allocation belongs to a value of its own.
+        let allocated = if self.depth == 0 {
+            size
+        } else {
+            self.allocated.saturating_add(size)
+        };
+
+        if allocated > self.max_allocation {
+            return Err(ByteBufferError::AllocationLimit {
+                size: allocated,
+                max: self.max_allocation,
+            });
+        }
+
+        if self.depth != 0 {
+            self.allocated = allocated;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn enter(&mut self) -> Result<()> {
+        if self.depth >= self.max_depth {
+            return Err(ByteBufferError::DepthLimit {
+                max: self.max_depth,
+            });
+        }
+
+        self.depth += 1;
+
+        Ok(())
+    }
+
+    pub(crate) fn exit(&mut self) {
+        self.depth -= 1;
+
+        if self.depth == 0 {
+            self.allocated = 0;
+        }
+    }
+}
+
+impl Default for DecodeLimits {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PartialEq for DecodeLimits {
+    /// Compares the limits, the bytes and depth counted while reading are ignored.
+    fn eq(&self, other: &Self) -> bool {
+        self.max_collection_length == other.max_collection_length
+            && self.max_string_length == other.max_string_length
+            && self.max_allocation == other.max_allocation
+            && self.max_depth == other.max_depth
+    }
+}
+
+impl Eq for DecodeLimits {}
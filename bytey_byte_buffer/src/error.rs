@@ -1,6 +1,10 @@
-pub type Result<T> = std::result::Result<T, ByteBufferError>;
+use alloc::{boxed::Box, string::String, vec, vec::Vec};
+use core::fmt::{self, Display};
+
+pub type Result<T> = core::result::Result<T, ByteBufferError>;
 
 #[derive(thiserror::Error, Debug, Eq, PartialEq)]
+#[non_exhaustive]
 pub enum ByteBufferError {
     #[error("Capacity cannot be greater than {} bytes", isize::MAX)]
     MaxCapacity,
@@ -31,7 +35,7 @@ pub enum ByteBufferError {
     OtherError { error: String },
 
     #[error(transparent)]
-    UnicodeError(#[from] std::str::Utf8Error),
+    UnicodeError(#[from] core::str::Utf8Error),
 
     #[error("RefCellAlreadyBorrowed: {error} for type: {type_name}")]
     RefCellAlreadyBorrowed {
@@ -46,4 +50,197 @@ pub enum ByteBufferError {
         "the value can not be a char. Maybe you have it set to the wrong position in the struct?"
     )]
     NotAChar,
+    #[error("Array length mismatch, expected {expected} elements but found {found}")]
+    ArrayLengthMismatch { expected: usize, found: usize },
+    #[error("Invalid tag {tag} for {type_name}")]
+    InvalidTag {
+        /// The name of the type being read.
+        type_name: &'static str,
+        /// The tag that doesn't belong to any variant of the type.
+        tag: u64,
+    },
+    #[error("Variable length integer does not fit in {type_name}")]
+    VarIntOverflow { type_name: &'static str },
+    #[error("Variable length integer for {type_name} is not minimally encoded")]
+    VarIntOverlong { type_name: &'static str },
+    #[error("Length {length} does not fit in {target}")]
+    LengthOverflow {
+        /// The length being written or read.
+        length: u64,
+        /// The length prefix or type the length has to fit in.
+        target: &'static str,
+    },
+    #[error("Incomplete frame, {needed} more bytes are needed")]
+    Incomplete {
+        /// The least amount of bytes that have to be added before the frame can be read.
+        needed: usize,
+    },
+    #[error("{remaining} bytes are left in the frame after the value")]
+    TrailingBytes {
+        /// The amount of bytes of the frame that weren't read.
+        remaining: usize,
+    },
+    #[error("Collection length {length} exceeds the limit of {max}")]
+    CollectionTooLong { length: usize, max: usize },
+    #[error("String length {length} exceeds the limit of {max}")]
+    StringTooLong { length: usize, max: usize },
+    #[error("Reading the value allocates {size} bytes, exceeding the limit of {max}")]
+    AllocationLimit {
+        /// The bytes allocated by the value so far, including the allocation that exceeded the limit.
+        size: usize,
+        max: usize,
+    },
+    #[error("Nesting depth exceeds the limit of {max}")]
+    DepthLimit { max: usize },
+    #[error("Duplicate key in a canonically encoded map or set")]
+    DuplicateKey,
+    #[error("{error} in {context}")]
+    Context {
+        /// Where the error happened.
+        context: ErrorContext,
+        /// The error that happened.
+        error: Box<ByteBufferError>,
+    },
+    #[cfg(feature = "std")]
+    #[error("IO Error: {error}")]
+    Io {
+        /// The kind of the underlying [`std::io::Error`].
+        kind: std::io::ErrorKind,
+        /// The message of the underlying [`std::io::Error`].
+        error: String,
+    },
+}
+
+impl ByteBufferError {
+    /// Returns the error without the [`ErrorContext`] that was attached while reading.
+    ///
+    /// # Examples
+    /// ```
+    /// use bytey_byte_buffer::error::ByteBufferError;
+    ///
+    /// let error = ByteBufferError::NotAChar.with_index(3, Some(12));
+    ///
+    /// assert_eq!(error.root(), &ByteBufferError::NotAChar);
+    /// assert_eq!(error.to_string(), format!("{} in [3] at byte 12", ByteBufferError::NotAChar));
+    /// ```
+    pub fn root(&self) -> &ByteBufferError {
+        match self {
+            ByteBufferError::Context { error, .. } => error,
+            error => error,
+        }
+    }
+
+    /// Returns the [`ErrorContext`] of the error, if it happened while reading a field or element.
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            ByteBufferError::Context { context, .. } => Some(context),
+            _ => None,
+        }
+    }
+
+    /// Adds the field of the struct or enum variant the error happened in to the path of the [`ErrorContext`].
+    ///
+    /// The offset is only kept if the error has no context yet, as the innermost offset is where the error happened.
+    pub fn with_field(
+        self,
+        type_name: &'static str,
+        field: &'static str,
+        offset: Option<usize>,
+    ) -> Self {
+        self.with_segment(PathSegment::Field { type_name, field }, offset)
+    }
+
+    /// Adds the index of the collection element the error happened in to the path of the [`ErrorContext`].
+    ///
+    /// The offset is only kept if the error has no context yet, as the innermost offset is where the error happened.
+    pub fn with_index(self, index: usize, offset: Option<usize>) -> Self {
+        self.with_segment(PathSegment::Index(index), offset)
+    }
+
+    fn with_segment(self, segment: PathSegment, offset: Option<usize>) -> Self {
+        match self {
+            ByteBufferError::Context { mut context, error } => {
+                context.path.0.insert(0, segment);
+
+                ByteBufferError::Context { context, error }
+            }
+            error => ByteBufferError::Context {
+                context: ErrorContext {
+                    path: ErrorPath(vec![segment]),
+                    offset,
+                },
+                error: Box::new(error),
+            },
+        }
+    }
+}
+
+/// Where an error happened while reading a value, see [`ByteBufferError::Context`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ErrorContext {
+    path: ErrorPath,
+    offset: Option<usize>,
+}
+
+impl ErrorContext {
+    /// Returns the path from the value being read to the field or element the error happened in.
+    pub fn path(&self) -> &ErrorPath {
+        &self.path
+    }
+
+    /// Returns the position in the source where the error happened, if the source keeps track of it.
+    pub fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+}
+
+impl Display for ErrorContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.offset {
+            Some(offset) => write!(f, "{} at byte {offset}", self.path),
+            None => write!(f, "{}", self.path),
+        }
+    }
+}
+
+/// The fields and elements leading to a value, displayed like `Login.credentials.password[3]`.
+///
+/// Elements of tuples are named by their index and the entries of maps by `key` and `value`, so a bad value of the
+/// fourth entry of a map of tuples shows up as `[3].value.1`.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct ErrorPath(Vec<PathSegment>);
+
+impl ErrorPath {
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.0
+    }
+}
+
+impl Display for ErrorPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.0.iter().enumerate() {
+            match segment {
+                // Only the outermost type is named, the types of the fields follow from it.
+                PathSegment::Field { type_name, field } if i == 0 => {
+                    write!(f, "{type_name}.{field}")?
+                }
+                PathSegment::Field { field, .. } => write!(f, ".{field}")?,
+                PathSegment::Index(index) => write!(f, "[{index}]")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A step of an [`ErrorPath`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum PathSegment {
+    /// A field of a struct, enum variant, tuple or map entry, tuple fields are named by their index.
+    Field {
+        type_name: &'static str,
+        field: &'static str,
+    },
+    /// An element of a collection.
+    Index(usize),
 }
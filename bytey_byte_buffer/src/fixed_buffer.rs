@@ -0,0 +1,390 @@
+//! Buffers with a fixed capacity that never allocate.
+//!
+//! [`ArrayByteBuffer`] stores its bytes inline, e.g. on the stack, and [`SliceBuffer`] writes into a borrowed slice.
+//! Both implement [`Sink`] and [`Source`], so every type implementing [`ByteBufferWrite`] and [`ByteBufferRead`]
+//! can be written to and read from them. Writing past the capacity returns [`ByteBufferError::MaxCapacity`]
+//! instead of growing the buffer.
+
+use crate::{
+    byte_buffer_read::ByteBufferRead,
+    byte_buffer_ref::ByteBufferRef,
+    byte_buffer_write::ByteBufferWrite,
+    decode_limits::DecodeLimits,
+    error::{ByteBufferError, Result},
+    length_prefix::LengthPrefix,
+    stream::{Sink, Source},
+};
+
+macro_rules! impl_fixed_buffer {
+    ([$($generics:tt)*] $type:ty) => {
+        impl<$($generics)*> $type {
+            /// Writes a slice of type [u8] at the current cursor position.
+            ///
+            /// # Behaviour
+            /// The current cursor position will be increased by the length of the slice.
+            ///
+            /// # Errors
+            /// - [`ByteBufferError::MaxCapacity`] is returned if the slice doesn't fit in the remaining capacity,
+            ///   nothing is written.
+            pub fn write_slice(&mut self, source: &[u8]) -> Result<&mut Self> {
+                let end = self.cursor + source.len();
+
+                if end > self.data.len() {
+                    return Err(ByteBufferError::MaxCapacity);
+                }
+
+                self.data[self.cursor..end].copy_from_slice(source);
+                self.cursor = end;
+
+                if self.cursor > self.length {
+                    self.length = self.cursor;
+                }
+
+                Ok(self)
+            }
+
+            /// Writes the given value, see [`ByteBuffer::write`](crate::byte_buffer::ByteBuffer::write).
+            ///
+            /// # Errors
+            /// See [`write_slice`](Self::write_slice).
+            pub fn write<T: ByteBufferWrite>(&mut self, source: T) -> Result<&mut Self> {
+                source.write_to_bytey_buffer(self)?;
+
+                Ok(self)
+            }
+
+            /// Writes the given value in **little endian** ordering.
+            ///
+            /// # Errors
+            /// See [`write_slice`](Self::write_slice).
+            pub fn write_le<T: ByteBufferWrite>(&mut self, source: T) -> Result<&mut Self> {
+                source.write_to_bytey_buffer_le(self)?;
+
+                Ok(self)
+            }
+
+            /// Writes the given value in **big endian** ordering.
+            ///
+            /// # Errors
+            /// See [`write_slice`](Self::write_slice).
+            pub fn write_be<T: ByteBufferWrite>(&mut self, source: T) -> Result<&mut Self> {
+                source.write_to_bytey_buffer_be(self)?;
+
+                Ok(self)
+            }
+
+            /// Reads a slice of type [u8] of the given size.
+            ///
+            /// # Behaviour
+            /// The current cursor position will be increased by the given size.
+            ///
+            /// # Errors
+            /// - [`ByteBufferError::ReadOutOfBounds`] is returned if the result of the current cursor position + the given size exceeds the length
+            pub fn read_slice(&mut self, size: usize) -> Result<&[u8]> {
+                if self.cursor.checked_add(size).is_none_or(|end| end > self.length) {
+                    return Err(ByteBufferError::ReadOutOfBounds {
+                        length: self.length,
+                        start: self.cursor,
+                        end: self.cursor.saturating_add(size),
+                    });
+                }
+
+                let start = self.cursor;
+                self.cursor += size;
+
+                Ok(&self.data[start..self.cursor])
+            }
+
+            /// Reads a value of type T, see [`ByteBuffer::read`](crate::byte_buffer::ByteBuffer::read).
+            ///
+            /// # Errors
+            /// See [`read_slice`](Self::read_slice).
+            pub fn read<T: ByteBufferRead>(&mut self) -> Result<T> {
+                T::read_from_bytey_buffer(self)
+            }
+
+            /// Reads a value of type T in **little endian** ordering.
+            ///
+            /// # Errors
+            /// See [`read_slice`](Self::read_slice).
+            pub fn read_le<T: ByteBufferRead>(&mut self) -> Result<T> {
+                T::read_from_bytey_buffer_le(self)
+            }
+
+            /// Reads a value of type T in **big endian** ordering.
+            ///
+            /// # Errors
+            /// See [`read_slice`](Self::read_slice).
+            pub fn read_be<T: ByteBufferRead>(&mut self) -> Result<T> {
+                T::read_from_bytey_buffer_be(self)
+            }
+
+            /// Returns a [`ByteBufferRef`] reading from the current cursor position, see
+            /// [`ByteBuffer::reader`](crate::byte_buffer::ByteBuffer::reader).
+            pub fn reader(&self) -> ByteBufferRef<'_> {
+                ByteBufferRef::with_cursor(
+                    &self.data[..self.length],
+                    self.cursor,
+                    self.length_prefix,
+                    self.canonical,
+                    self.decode_limits.reset(),
+                )
+            }
+
+            /// Moves the current cursor position.
+            ///
+            /// # Errors
+            /// - [`ByteBufferError::CursorOutOfBounds`] if the cursor exceeds the length
+            pub fn move_cursor(&mut self, location: usize) -> Result<&mut Self> {
+                if location > self.length {
+                    return Err(ByteBufferError::CursorOutOfBounds {
+                        length: self.length,
+                        cursor: location,
+                    });
+                }
+
+                self.cursor = location;
+
+                Ok(self)
+            }
+
+            /// Sets the length to 0 and moves the cursor to the start, so the buffer can be reused.
+            pub fn clear(&mut self) -> &mut Self {
+                self.length = 0;
+                self.cursor = 0;
+
+                self
+            }
+
+            /// Returns the bytes that have been written, up to the length.
+            pub fn as_bytes(&self) -> &[u8] {
+                &self.data[..self.length]
+            }
+
+            /// Returns the amount of bytes that have been written.
+            pub fn length(&self) -> usize {
+                self.length
+            }
+
+            /// Returns the amount of bytes that can be stored.
+            pub fn capacity(&self) -> usize {
+                self.data.len()
+            }
+
+            /// Returns the current cursor position.
+            pub fn cursor(&self) -> usize {
+                self.cursor
+            }
+
+            /// Returns true if the length is 0
+            pub fn is_empty(&self) -> bool {
+                self.length == 0
+            }
+
+            /// Returns the [`LengthPrefix`] used to write and read the length of strings and collections.
+            pub fn length_prefix(&self) -> LengthPrefix {
+                self.length_prefix
+            }
+
+            /// Sets the [`LengthPrefix`] used to write and read the length of strings and collections.
+            pub fn set_length_prefix(&mut self, length_prefix: LengthPrefix) -> &mut Self {
+                self.length_prefix = length_prefix;
+
+                self
+            }
+
+            /// Returns true if maps and sets are written in canonical order and duplicate keys are rejected.
+            pub fn canonical(&self) -> bool {
+                self.canonical
+            }
+
+            /// Sets whether maps and sets are written in canonical order and duplicate keys are rejected, see
+            /// [`ByteBuffer::set_canonical`](crate::byte_buffer::ByteBuffer::set_canonical).
+            pub fn set_canonical(&mut self, canonical: bool) -> &mut Self {
+                self.canonical = canonical;
+
+                self
+            }
+
+            /// Returns the [`DecodeLimits`] enforced while reading values.
+            pub fn decode_limits(&self) -> DecodeLimits {
+                self.decode_limits
+            }
+
+            /// Sets the [`DecodeLimits`] enforced while reading values, see
+            /// [`ByteBuffer::set_decode_limits`](crate::byte_buffer::ByteBuffer::set_decode_limits).
+            pub fn set_decode_limits(&mut self, decode_limits: DecodeLimits) -> &mut Self {
+                self.decode_limits = decode_limits.reset();
+
+                self
+            }
+        }
+
+        impl<$($generics)*> Sink for $type {
+            #[inline]
+            fn write_slice(&mut self, source: &[u8]) -> Result<()> {
+                <$type>::write_slice(self, source)?;
+
+                Ok(())
+            }
+
+            #[inline]
+            fn length_prefix(&self) -> LengthPrefix {
+                self.length_prefix
+            }
+
+            #[inline]
+            fn canonical(&self) -> bool {
+                self.canonical
+            }
+        }
+
+        impl<$($generics)*> Source for $type {
+            #[inline]
+            fn read_slice(&mut self, size: usize) -> Result<&[u8]> {
+                <$type>::read_slice(self, size)
+            }
+
+            #[inline]
+            fn length_prefix(&self) -> LengthPrefix {
+                self.length_prefix
+            }
+
+            #[inline]
+            fn canonical(&self) -> bool {
+                self.canonical
+            }
+
+            #[inline]
+            fn offset(&self) -> Option<usize> {
+                Some(self.cursor)
+            }
+
+            #[inline]
+            fn decode_limits_mut(&mut self) -> Option<&mut DecodeLimits> {
+                Some(&mut self.decode_limits)
+            }
+        }
+    };
+}
+
+/// A buffer storing up to N bytes inline, without allocating.
+///
+/// # Examples
+/// ```
+/// use bytey_byte_buffer::{error::ByteBufferError, fixed_buffer::ArrayByteBuffer};
+///
+/// let mut buffer = ArrayByteBuffer::<6>::new();
+///
+/// buffer.write(1u32).unwrap();
+/// assert_eq!(buffer.write(2u32).unwrap_err(), ByteBufferError::MaxCapacity);
+///
+/// buffer.move_cursor(0).unwrap();
+/// assert_eq!(buffer.read::<u32>().unwrap(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ArrayByteBuffer<const N: usize> {
+    data: [u8; N],
+    length: usize,
+    cursor: usize,
+    length_prefix: LengthPrefix,
+    canonical: bool,
+    decode_limits: DecodeLimits,
+}
+
+impl<const N: usize> ArrayByteBuffer<N> {
+    pub const fn new() -> Self {
+        Self {
+            data: [0; N],
+            length: 0,
+            cursor: 0,
+            // LengthPrefix::default() can't be called in a const fn.
+            length_prefix: LengthPrefix::U64,
+            canonical: false,
+            decode_limits: DecodeLimits::new(),
+        }
+    }
+}
+
+impl<const N: usize> Default for ArrayByteBuffer<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl_fixed_buffer!([const N: usize] ArrayByteBuffer<N>);
+
+/// A buffer writing into a borrowed slice, without allocating.
+///
+/// # Examples
+/// ```
+/// use bytey_byte_buffer::fixed_buffer::SliceBuffer;
+///
+/// let mut bytes = [0u8; 16];
+/// let mut buffer = SliceBuffer::new(&mut bytes);
+///
+/// buffer.write_be(0x0102u16).unwrap();
+/// assert_eq!(buffer.as_bytes(), [1, 2]);
+/// ```
+#[derive(Debug)]
+pub struct SliceBuffer<'a> {
+    data: &'a mut [u8],
+    length: usize,
+    cursor: usize,
+    length_prefix: LengthPrefix,
+    canonical: bool,
+    decode_limits: DecodeLimits,
+}
+
+impl<'a> SliceBuffer<'a> {
+    /// Constructs a new empty [`SliceBuffer`], the capacity is the length of the slice.
+    pub fn new(data: &'a mut [u8]) -> Self {
+        Self {
+            data,
+            length: 0,
+            cursor: 0,
+            length_prefix: LengthPrefix::default(),
+            canonical: false,
+            decode_limits: DecodeLimits::new(),
+        }
+    }
+
+    /// Constructs a [`SliceBuffer`] holding the first `length` bytes of the slice, e.g. bytes that were already
+    /// received into it, with the cursor at position 0.
+    ///
+    /// # Errors
+    /// - [`ByteBufferError::LengthOutOfBounds`] is returned if the length exceeds the length of the slice.
+    ///
+    /// # Examples
+    /// ```
+    /// use bytey_byte_buffer::fixed_buffer::SliceBuffer;
+    ///
+    /// let mut bytes = [0u8; 16];
+    /// bytes[..2].copy_from_slice(&[1, 2]);
+    ///
+    /// let mut buffer = SliceBuffer::from_filled(&mut bytes, 2).unwrap();
+    ///
+    /// assert_eq!(buffer.read_be::<u16>().unwrap(), 0x0102);
+    /// assert_eq!(buffer.capacity(), 16);
+    /// ```
+    pub fn from_filled(data: &'a mut [u8], length: usize) -> Result<Self> {
+        if length > data.len() {
+            return Err(ByteBufferError::LengthOutOfBounds {
+                current: data.len(),
+                new: length,
+            });
+        }
+
+        let mut buffer = Self::new(data);
+        buffer.length = length;
+
+        Ok(buffer)
+    }
+
+    /// Returns the borrowed slice.
+    pub fn into_inner(self) -> &'a mut [u8] {
+        self.data
+    }
+}
+
+impl_fixed_buffer!(['a] SliceBuffer<'a>);
@@ -0,0 +1,241 @@
+//! A reader splitting a stream of bytes into length-delimited frames.
+//!
+//! Every frame is a length prefix followed by that many bytes, the length is written in **big endian** ordering.
+//! This is the same format the `ByteyCodec` of the `tokio` feature uses.
+
+use crate::{
+    byte_buffer::ByteBuffer,
+    byte_buffer_read::ByteBufferRead,
+    byte_buffer_ref::ByteBufferRef,
+    decode_limits::DecodeLimits,
+    error::{ByteBufferError, Result},
+    length_prefix::{Length, LengthPrefix},
+};
+
+/// The default largest frame that is read, 8 MiB.
+pub const DEFAULT_MAX_FRAME_LENGTH: usize = 8 * 1024 * 1024;
+
+/// Accumulates incoming chunks of bytes and pops the frames that are complete.
+///
+/// Reading a frame that hasn't fully arrived returns [`ByteBufferError::Incomplete`] with the amount of bytes that
+/// are still missing, so it can be told apart from corrupt data. Popped frames are removed from the underlying
+/// [`ByteBuffer`] by moving the remaining bytes to the start of its allocation, so a reader that keeps up with the
+/// incoming data doesn't have to reallocate.
+///
+/// # Examples
+/// ```
+/// use bytey_byte_buffer::{error::ByteBufferError, frame_reader::FrameReader};
+///
+/// let mut reader = FrameReader::new().unwrap();
+///
+/// reader.write_slice(&[0, 0, 0, 3, b'a']).unwrap();
+/// assert_eq!(reader.next_frame(), Err(ByteBufferError::Incomplete { needed: 2 }));
+///
+/// reader.write_slice(&[b'b', b'c', 0, 0]).unwrap();
+/// assert_eq!(reader.next_frame().unwrap(), b"abc");
+/// assert_eq!(reader.next_frame(), Err(ByteBufferError::Incomplete { needed: 2 }));
+/// ```
+#[derive(Debug)]
+pub struct FrameReader {
+    buffer: ByteBuffer,
+    length_prefix: LengthPrefix,
+    max_frame_length: usize,
+}
+
+impl FrameReader {
+    /// Constructs a new [`FrameReader`] with a buffer of capacity [`MIN_SIZE`](ByteBuffer::MIN_SIZE).
+    pub fn new() -> Result<Self> {
+        Self::with_capacity(ByteBuffer::MIN_SIZE)
+    }
+
+    /// Constructs a new [`FrameReader`] with a buffer of the given capacity.
+    ///
+    /// # Errors
+    /// See [`ByteBuffer::with_capacity`].
+    pub fn with_capacity(capacity: usize) -> Result<Self> {
+        Ok(Self {
+            buffer: ByteBuffer::with_capacity(capacity)?,
+            length_prefix: LengthPrefix::U32,
+            max_frame_length: DEFAULT_MAX_FRAME_LENGTH,
+        })
+    }
+
+    /// Returns the [`LengthPrefix`] of a frame, the default is [`LengthPrefix::U32`].
+    pub fn length_prefix(&self) -> LengthPrefix {
+        self.length_prefix
+    }
+
+    /// Sets the [`LengthPrefix`] of a frame.
+    pub fn set_length_prefix(&mut self, length_prefix: LengthPrefix) -> &mut Self {
+        self.length_prefix = length_prefix;
+
+        self
+    }
+
+    /// Returns the largest length of a frame, without the length prefix.
+    pub fn max_frame_length(&self) -> usize {
+        self.max_frame_length
+    }
+
+    /// Sets the largest length of a frame, without the length prefix.
+    ///
+    /// Reading a larger frame returns [`ByteBufferError::LengthOverflow`], so a peer can't make the reader
+    /// buffer an arbitrary amount of memory.
+    pub fn set_max_frame_length(&mut self, max_frame_length: usize) -> &mut Self {
+        self.max_frame_length = max_frame_length;
+
+        self
+    }
+
+    /// Returns the [`DecodeLimits`] enforced by [`read_frame`](Self::read_frame).
+    pub fn decode_limits(&self) -> DecodeLimits {
+        self.buffer.decode_limits()
+    }
+
+    /// Sets the [`DecodeLimits`] enforced by [`read_frame`](Self::read_frame), see
+    /// [`ByteBuffer::set_decode_limits`].
+    ///
+    /// The limits apply to every frame on its own.
+    pub fn set_decode_limits(&mut self, decode_limits: DecodeLimits) -> &mut Self {
+        self.buffer.set_decode_limits(decode_limits);
+
+        self
+    }
+
+    /// Returns the amount of bytes that have been written but not popped yet.
+    pub fn buffered(&self) -> usize {
+        self.buffer.length() - self.buffer.cursor()
+    }
+
+    /// Returns the capacity of the underlying [`ByteBuffer`].
+    pub fn capacity(&self) -> usize {
+        self.buffer.capacity()
+    }
+
+    /// Appends a chunk of incoming bytes.
+    ///
+    /// # Behaviour
+    /// If the chunk doesn't fit in the capacity, the bytes of the popped frames are removed first.
+    /// The buffer only grows if the chunk still doesn't fit.
+    ///
+    /// # Errors
+    /// See [`ByteBuffer::write_slice`].
+    pub fn write_slice(&mut self, source: &[u8]) -> Result<&mut Self> {
+        if self.buffered() == 0 || self.buffer.length() + source.len() > self.buffer.capacity() {
+            self.buffer.compact();
+        }
+
+        let cursor = self.buffer.cursor();
+
+        self.buffer.move_cursor_to_end().write_slice(source)?;
+        self.buffer.move_cursor(cursor)?;
+
+        Ok(self)
+    }
+
+    /// Pops the next frame and returns its bytes, without the length prefix.
+    ///
+    /// # Errors
+    /// - [`ByteBufferError::Incomplete`] is returned if the frame hasn't fully arrived yet, nothing is popped.
+    /// - [`ByteBufferError::LengthOverflow`] is returned if the frame is larger than the
+    ///   [`max_frame_length`](Self::max_frame_length).
+    pub fn next_frame(&mut self) -> Result<&[u8]> {
+        let (header, length) = frame_header(
+            self.buffer.remaining(),
+            self.length_prefix,
+            self.max_frame_length,
+        )?;
+        let start = self.buffer.cursor() + header;
+
+        self.buffer.move_cursor(start)?;
+        self.buffer.read_slice(length)
+    }
+
+    /// Pops the next frame and reads a value of type T from it in **big endian** ordering.
+    ///
+    /// The frame is popped as soon as it is complete, even if reading the value fails.
+    ///
+    /// # Errors
+    /// - See [`next_frame`](Self::next_frame).
+    /// - [`ByteBufferError::ReadOutOfBounds`] is returned if the value is larger than the frame.
+    /// - [`ByteBufferError::TrailingBytes`] is returned if the value is smaller than the frame.
+    /// - Any error returned by the [`ByteBufferRead`] impl of T.
+    pub fn read_frame<T: ByteBufferRead>(&mut self) -> Result<T> {
+        let (header, length) = frame_header(
+            self.buffer.remaining(),
+            self.length_prefix,
+            self.max_frame_length,
+        )?;
+        let start = self.buffer.cursor() + header;
+        let end = start + length;
+        let written = self.buffer.length();
+
+        // Hide the bytes after the frame, so the value can't be read from the next frame.
+        self.buffer.truncate(end)?.move_cursor(start)?;
+
+        let value = self
+            .buffer
+            .read_be::<T>()
+            .and_then(|value| check_trailing(end, self.buffer.cursor()).map(|()| value));
+
+        // SAFETY: the bytes up to the old length were written before the truncate.
+        unsafe {
+            self.buffer.set_length(written);
+        }
+
+        self.buffer.move_cursor(end)?;
+
+        value
+    }
+}
+
+/// Reads the length prefix at the start of `data` and returns the size of the prefix and the length of the frame.
+///
+/// [`ByteBufferError::Incomplete`] is returned if `data` doesn't hold the whole frame yet.
+pub(crate) fn frame_header(
+    data: &[u8],
+    length_prefix: LengthPrefix,
+    max_frame_length: usize,
+) -> Result<(usize, usize)> {
+    let mut reader = ByteBufferRef::new(data);
+
+    reader.set_length_prefix(length_prefix);
+
+    let length = match reader.read_be::<Length>() {
+        Ok(Length(length)) => length,
+        Err(ByteBufferError::ReadOutOfBounds { length, end, .. }) => {
+            return Err(ByteBufferError::Incomplete {
+                needed: end - length,
+            });
+        }
+        Err(error) => return Err(error),
+    };
+
+    if length > max_frame_length {
+        return Err(ByteBufferError::LengthOverflow {
+            length: length as u64,
+            target: "the max frame length",
+        });
+    }
+
+    let header = reader.cursor();
+
+    if data.len() - header < length {
+        return Err(ByteBufferError::Incomplete {
+            needed: length - (data.len() - header),
+        });
+    }
+
+    Ok((header, length))
+}
+
+/// Returns [`ByteBufferError::TrailingBytes`] if a value ending at `read` didn't use the whole frame ending at `end`.
+pub(crate) fn check_trailing(end: usize, read: usize) -> Result<()> {
+    if read < end {
+        return Err(ByteBufferError::TrailingBytes {
+            remaining: end - read,
+        });
+    }
+
+    Ok(())
+}
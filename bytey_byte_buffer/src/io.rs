@@ -0,0 +1,109 @@
+//! [`std::io`] implementations for [`ByteBuffer`].
+//!
+//! [`Read`] and [`BufRead`] read from the cursor up to the length of the buffer, [`Write`] writes at the cursor
+//! and grows the buffer like [`write_slice`](ByteBuffer::write_slice) does.
+//! [`Seek`] uses the same bounds checks as [`move_cursor`](ByteBuffer::move_cursor), so seeking before the start
+//! or past the length of the buffer is an error.
+//!
+//! # Examples
+//! ```
+//! use bytey_byte_buffer::byte_buffer::ByteBuffer;
+//! use std::io::{self, Read, Seek, SeekFrom};
+//!
+//! let mut buffer = ByteBuffer::new().unwrap();
+//!
+//! io::copy(&mut &b"hello world"[..], &mut buffer).unwrap();
+//! buffer.seek(SeekFrom::End(-5)).unwrap();
+//!
+//! let mut text = String::new();
+//! buffer.read_to_string(&mut text).unwrap();
+//!
+//! assert_eq!(text, "world");
+//! ```
+
+use crate::{byte_buffer::ByteBuffer, error::ByteBufferError};
+use std::io::{self, BufRead, Read, Seek, SeekFrom, Write};
+
+impl From<ByteBufferError> for io::Error {
+    fn from(error: ByteBufferError) -> Self {
+        let kind = match error {
+            ByteBufferError::ReadOutOfBounds { .. } => io::ErrorKind::UnexpectedEof,
+            ByteBufferError::CursorOutOfBounds { .. } => io::ErrorKind::InvalidInput,
+            ByteBufferError::MaxCapacity | ByteBufferError::AllocationFailure { .. } => {
+                io::ErrorKind::OutOfMemory
+            }
+            ByteBufferError::Io { kind, .. } => kind,
+            _ => io::ErrorKind::Other,
+        };
+
+        io::Error::new(kind, error)
+    }
+}
+
+impl From<io::Error> for ByteBufferError {
+    fn from(error: io::Error) -> Self {
+        ByteBufferError::Io {
+            kind: error.kind(),
+            error: error.to_string(),
+        }
+    }
+}
+
+impl Read for ByteBuffer {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let size = buf.len().min(self.remaining().len());
+
+        buf[..size].copy_from_slice(self.read_slice(size)?);
+
+        Ok(size)
+    }
+}
+
+impl BufRead for ByteBuffer {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        Ok(self.remaining())
+    }
+
+    fn consume(&mut self, amt: usize) {
+        let cursor = (self.cursor() + amt).min(self.length());
+
+        // The cursor is clamped to the length, so this can't fail.
+        let _ = self.move_cursor(cursor);
+    }
+}
+
+impl Write for ByteBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.write_slice(buf)?;
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Seek for ByteBuffer {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let (base, offset) = match pos {
+            SeekFrom::Start(location) => (0, i128::from(location)),
+            SeekFrom::End(offset) => (self.length(), i128::from(offset)),
+            SeekFrom::Current(offset) => (self.cursor(), i128::from(offset)),
+        };
+
+        let location = base as i128 + offset;
+
+        if location < 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative position",
+            ));
+        }
+
+        let location = usize::try_from(location).unwrap_or(usize::MAX);
+        self.move_cursor(location)?;
+
+        Ok(location as u64)
+    }
+}
@@ -0,0 +1,92 @@
+use crate::error::{ByteBufferError, Result};
+
+/// The integer type used to store the length of strings and collections.
+///
+/// The prefix is a setting of the [`ByteBuffer`](crate::byte_buffer::ByteBuffer), see
+/// [`set_length_prefix`](crate::byte_buffer::ByteBuffer::set_length_prefix). The buffer that reads the data
+/// has to use the same prefix as the buffer that wrote it.
+///
+/// # Examples
+/// ```
+/// use bytey_byte_buffer::{byte_buffer::ByteBuffer, length_prefix::LengthPrefix};
+///
+/// let mut buffer = ByteBuffer::new().unwrap();
+///
+/// buffer.set_length_prefix(LengthPrefix::U8);
+/// buffer.write("hello").unwrap();
+/// assert_eq!(buffer.length(), 6);
+///
+/// buffer.move_cursor(0).unwrap();
+/// assert_eq!(buffer.read::<String>().unwrap(), "hello");
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum LengthPrefix {
+    U8,
+    U16,
+    U32,
+    /// The default, 8 bytes on every platform.
+    #[default]
+    U64,
+    /// An unsigned LEB128 integer, see [`VarU64`](crate::varint::VarU64).
+    VarInt,
+}
+
+impl LengthPrefix {
+    /// The largest length that can be stored with this prefix.
+    pub fn max(&self) -> u64 {
+        match self {
+            LengthPrefix::U8 => u8::MAX.into(),
+            LengthPrefix::U16 => u16::MAX.into(),
+            LengthPrefix::U32 => u32::MAX.into(),
+            LengthPrefix::U64 | LengthPrefix::VarInt => u64::MAX,
+        }
+    }
+
+    /// The name of the integer type, used as the target of [`ByteBufferError::LengthOverflow`].
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            LengthPrefix::U8 => "u8",
+            LengthPrefix::U16 => "u16",
+            LengthPrefix::U32 => "u32",
+            LengthPrefix::U64 => "u64",
+            LengthPrefix::VarInt => "varint",
+        }
+    }
+}
+
+/// The length of a string or collection, stored with the [`LengthPrefix`] of the buffer.
+///
+/// Useful to write the length of custom collections the same way the built-in ones do.
+///
+/// # Errors
+/// - [`ByteBufferError::LengthOverflow`] is returned when writing a length that does not fit in the prefix,
+///   or when reading a length that does not fit in a [`usize`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Length(pub usize);
+
+impl Length {
+    /// Converts the length to the integer type of the prefix.
+    pub(crate) fn fit<T: TryFrom<u64>>(&self, prefix: LengthPrefix) -> Result<T> {
+        let length = self.0 as u64;
+        let error = ByteBufferError::LengthOverflow {
+            length,
+            target: prefix.name(),
+        };
+
+        if length > prefix.max() {
+            return Err(error);
+        }
+
+        T::try_from(length).map_err(|_| error)
+    }
+
+    /// Converts a length read from the buffer.
+    pub(crate) fn from_u64(length: u64) -> Result<Length> {
+        usize::try_from(length)
+            .map(Length)
+            .map_err(|_| ByteBufferError::LengthOverflow {
+                length,
+                target: "usize",
+            })
+    }
+}
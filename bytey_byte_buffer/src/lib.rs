@@ -1,4 +1,23 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
 pub mod byte_buffer;
 pub mod byte_buffer_read;
+pub mod byte_buffer_read_ref;
+pub mod byte_buffer_ref;
 pub mod byte_buffer_write;
+#[cfg(feature = "bytes")]
+pub mod bytes;
+#[cfg(feature = "tokio")]
+pub mod codec;
+pub mod decode_limits;
 pub mod error;
+pub mod fixed_buffer;
+pub mod frame_reader;
+#[cfg(feature = "std")]
+pub mod io;
+pub mod length_prefix;
+pub mod no_uninit;
+pub mod stream;
+pub mod varint;
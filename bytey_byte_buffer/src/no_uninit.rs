@@ -0,0 +1,27 @@
+//! A marker for types whose raw bytes can be copied, used by unions derived with `#[bytey(union_as = "bytes")]`.
+
+/// Types without padding or other uninitialized bytes, for which every bit pattern is a valid value.
+///
+/// Copying the raw bytes of such a value never reads uninitialized memory, and copying any bytes into it never
+/// makes an invalid value.
+///
+/// # Safety
+/// The type has to be [`Copy`], have no padding bytes and be valid for any bit pattern of its size.
+/// Structs with fields of different alignment, `bool`, `char`, references and pointers don't qualify.
+pub unsafe trait NoUninit: Copy + 'static {}
+
+macro_rules! impl_no_uninit {
+    ($($type:ty),*) => {
+        $(
+            // SAFETY: primitive integers and floats have no padding and are valid for any bit pattern.
+            unsafe impl NoUninit for $type {}
+        )*
+    };
+}
+
+impl_no_uninit!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+
+// SAFETY: arrays have no padding between their elements.
+unsafe impl<T: NoUninit, const N: usize> NoUninit for [T; N] {}
@@ -0,0 +1,401 @@
+//! The [`Sink`] and [`Source`] traits that values are written to and read from.
+//!
+//! [`ByteBufferWrite`] and [`ByteBufferRead`] are generic over these traits, so every type that can be written
+//! to a [`ByteBuffer`] can also be streamed. [`IoSink`] and [`IoSource`] stream values through any
+//! [`std::io::Write`] or [`std::io::Read`] with the `std` feature, e.g. a `BufWriter<File>` or a `TcpStream`, without encoding the whole value
+//! into a buffer first.
+//!
+//! # Examples
+//! ```
+//! use bytey_byte_buffer::stream::{IoSink, IoSource, Sink, Source};
+//!
+//! let mut sink = IoSink::new(Vec::new());
+//!
+//! sink.write_be(vec![String::from("a"), String::from("b")]).unwrap();
+//! sink.write_be(7u16).unwrap();
+//!
+//! let bytes = sink.into_inner();
+//! let mut source = IoSource::new(&bytes[..]);
+//!
+//! assert_eq!(source.read_be::<Vec<String>>().unwrap(), ["a", "b"]);
+//! assert_eq!(source.read_be::<u16>().unwrap(), 7);
+//! ```
+
+#[cfg(feature = "std")]
+use crate::error::ByteBufferError;
+use crate::{
+    byte_buffer::ByteBuffer, byte_buffer_read::ByteBufferRead, byte_buffer_write::ByteBufferWrite,
+    decode_limits::DecodeLimits, error::Result, length_prefix::LengthPrefix,
+};
+#[cfg(feature = "std")]
+use std::io::{self, Read};
+
+/// A destination for encoded bytes.
+pub trait Sink: Sized {
+    /// Writes all of the given bytes.
+    fn write_slice(&mut self, source: &[u8]) -> Result<()>;
+
+    /// The [`LengthPrefix`] used to write the length of strings and collections.
+    fn length_prefix(&self) -> LengthPrefix {
+        LengthPrefix::default()
+    }
+
+    /// Whether maps and sets are written in canonical order, see [`ByteBuffer::set_canonical`].
+    fn canonical(&self) -> bool {
+        false
+    }
+
+    /// Writes the given value, see [`ByteBuffer::write`].
+    fn write<T: ByteBufferWrite>(&mut self, source: T) -> Result<()> {
+        source.write_to_bytey_buffer(self)
+    }
+
+    /// Writes the given value in **little endian** ordering, see [`ByteBuffer::write_le`].
+    fn write_le<T: ByteBufferWrite>(&mut self, source: T) -> Result<()> {
+        source.write_to_bytey_buffer_le(self)
+    }
+
+    /// Writes the given value in **big endian** ordering, see [`ByteBuffer::write_be`].
+    fn write_be<T: ByteBufferWrite>(&mut self, source: T) -> Result<()> {
+        source.write_to_bytey_buffer_be(self)
+    }
+}
+
+/// A source of encoded bytes.
+pub trait Source: Sized {
+    /// Reads exactly the given amount of bytes.
+    ///
+    /// # Errors
+    /// - [`ByteBufferError::ReadOutOfBounds`] is returned if there are not enough bytes left.
+    fn read_slice(&mut self, size: usize) -> Result<&[u8]>;
+
+    /// The [`LengthPrefix`] used to read the length of strings and collections.
+    fn length_prefix(&self) -> LengthPrefix {
+        LengthPrefix::default()
+    }
+
+    /// Whether maps and sets with duplicate keys are rejected, see [`ByteBuffer::set_canonical`].
+    fn canonical(&self) -> bool {
+        false
+    }
+
+    /// The amount of bytes read from the source, used as the offset of read errors.
+    ///
+    /// `None` if the source doesn't keep track of it.
+    fn offset(&self) -> Option<usize> {
+        None
+    }
+
+    /// The [`DecodeLimits`] enforced while reading, `None` if the source doesn't enforce any.
+    fn decode_limits_mut(&mut self) -> Option<&mut DecodeLimits> {
+        None
+    }
+
+    /// Checks a collection of `length` elements of type T against the [`DecodeLimits`] and counts the bytes
+    /// it allocates.
+    ///
+    /// Custom collections should call this after reading their length, before allocating.
+    ///
+    /// # Errors
+    /// - [`ByteBufferError::CollectionTooLong`] is returned if the collection is longer than the limit.
+    /// - [`ByteBufferError::AllocationLimit`] is returned if the value being read allocates more than the limit.
+    fn limit_collection<T>(&mut self, length: usize) -> Result<()> {
+        match self.decode_limits_mut() {
+            Some(limits) => limits.check_collection::<T>(length),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks a string of `length` bytes against the [`DecodeLimits`] and counts the bytes it allocates.
+    ///
+    /// # Errors
+    /// - [`ByteBufferError::StringTooLong`] is returned if the string is longer than the limit.
+    /// - [`ByteBufferError::AllocationLimit`] is returned if the value being read allocates more than the limit.
+    fn limit_string(&mut self, length: usize) -> Result<()> {
+        match self.decode_limits_mut() {
+            Some(limits) => limits.check_string(length),
+            None => Ok(()),
+        }
+    }
+
+    /// Counts an allocation of `size` bytes against the [`DecodeLimits`].
+    ///
+    /// # Errors
+    /// - [`ByteBufferError::AllocationLimit`] is returned if the value being read allocates more than the limit.
+    fn limit_allocation(&mut self, size: usize) -> Result<()> {
+        match self.decode_limits_mut() {
+            Some(limits) => limits.allocate(size),
+            None => Ok(()),
+        }
+    }
+
+    /// Reads a value one level deeper, checked against the max depth of the [`DecodeLimits`].
+    ///
+    /// Collections, boxes and derived types read their contents with this, so recursive types can't overflow
+    /// the stack.
+    ///
+    /// # Errors
+    /// - [`ByteBufferError::DepthLimit`] is returned if the value is nested deeper than the limit.
+    /// - Any error returned by `read`.
+    fn read_nested<T>(&mut self, read: impl FnOnce(&mut Self) -> Result<T>) -> Result<T> {
+        if let Some(limits) = self.decode_limits_mut() {
+            limits.enter()?;
+        }
+
+        let value = read(self);
+
+        if let Some(limits) = self.decode_limits_mut() {
+            limits.exit();
+        }
+
+        value
+    }
+
+    /// Reads a value of type T, see [`ByteBuffer::read`].
+    fn read<T: ByteBufferRead>(&mut self) -> Result<T> {
+        T::read_from_bytey_buffer(self)
+    }
+
+    /// Reads a value of type T in **little endian** ordering, see [`ByteBuffer::read_le`].
+    fn read_le<T: ByteBufferRead>(&mut self) -> Result<T> {
+        T::read_from_bytey_buffer_le(self)
+    }
+
+    /// Reads a value of type T in **big endian** ordering, see [`ByteBuffer::read_be`].
+    fn read_be<T: ByteBufferRead>(&mut self) -> Result<T> {
+        T::read_from_bytey_buffer_be(self)
+    }
+}
+
+impl Sink for ByteBuffer {
+    #[inline]
+    fn write_slice(&mut self, source: &[u8]) -> Result<()> {
+        ByteBuffer::write_slice(self, source)?;
+
+        Ok(())
+    }
+
+    #[inline]
+    fn length_prefix(&self) -> LengthPrefix {
+        ByteBuffer::length_prefix(self)
+    }
+
+    #[inline]
+    fn canonical(&self) -> bool {
+        ByteBuffer::canonical(self)
+    }
+}
+
+impl Source for ByteBuffer {
+    #[inline]
+    fn read_slice(&mut self, size: usize) -> Result<&[u8]> {
+        ByteBuffer::read_slice(self, size)
+    }
+
+    #[inline]
+    fn length_prefix(&self) -> LengthPrefix {
+        ByteBuffer::length_prefix(self)
+    }
+
+    #[inline]
+    fn canonical(&self) -> bool {
+        ByteBuffer::canonical(self)
+    }
+
+    #[inline]
+    fn offset(&self) -> Option<usize> {
+        Some(self.cursor())
+    }
+
+    #[inline]
+    fn decode_limits_mut(&mut self) -> Option<&mut DecodeLimits> {
+        Some(self.limits_mut())
+    }
+}
+
+#[cfg(feature = "std")]
+/// A [`Sink`] writing straight to an [`io::Write`].
+///
+/// Every value is passed to the writer as it is encoded, wrap unbuffered writers like a `TcpStream` in an
+/// [`io::BufWriter`] to avoid many small writes.
+#[derive(Debug)]
+pub struct IoSink<W: io::Write> {
+    writer: W,
+    length_prefix: LengthPrefix,
+    canonical: bool,
+}
+
+#[cfg(feature = "std")]
+impl<W: io::Write> IoSink<W> {
+    /// Constructs a new [`IoSink`] writing to the given writer with the default settings.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            length_prefix: LengthPrefix::default(),
+            canonical: false,
+        }
+    }
+
+    /// Sets the [`LengthPrefix`] used to write the length of strings and collections.
+    pub fn set_length_prefix(&mut self, length_prefix: LengthPrefix) -> &mut Self {
+        self.length_prefix = length_prefix;
+
+        self
+    }
+
+    /// Sets whether maps and sets are written in canonical order, see [`ByteBuffer::set_canonical`].
+    pub fn set_canonical(&mut self, canonical: bool) -> &mut Self {
+        self.canonical = canonical;
+
+        self
+    }
+
+    /// Flushes the underlying writer.
+    pub fn flush(&mut self) -> Result<()> {
+        self.writer.flush().map_err(ByteBufferError::from)
+    }
+
+    /// Returns a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Returns a mutable reference to the underlying writer, writing to it directly corrupts the stream of values.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Returns the underlying writer, without flushing it.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: io::Write> Sink for IoSink<W> {
+    #[inline]
+    fn write_slice(&mut self, source: &[u8]) -> Result<()> {
+        self.writer.write_all(source).map_err(ByteBufferError::from)
+    }
+
+    #[inline]
+    fn length_prefix(&self) -> LengthPrefix {
+        self.length_prefix
+    }
+
+    #[inline]
+    fn canonical(&self) -> bool {
+        self.canonical
+    }
+}
+
+#[cfg(feature = "std")]
+/// A [`Source`] reading straight from an [`io::Read`].
+///
+/// Only the bytes of the slice that is currently read are kept in memory, wrap unbuffered readers like a
+/// `TcpStream` in an [`io::BufReader`] to avoid many small reads.
+#[derive(Debug)]
+pub struct IoSource<R: io::Read> {
+    reader: R,
+    scratch: Vec<u8>,
+    offset: usize,
+    length_prefix: LengthPrefix,
+    canonical: bool,
+    decode_limits: DecodeLimits,
+}
+
+#[cfg(feature = "std")]
+impl<R: io::Read> IoSource<R> {
+    /// Constructs a new [`IoSource`] reading from the given reader with the default settings.
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            scratch: Vec::new(),
+            offset: 0,
+            length_prefix: LengthPrefix::default(),
+            canonical: false,
+            decode_limits: DecodeLimits::new(),
+        }
+    }
+
+    /// Sets the [`LengthPrefix`] used to read the length of strings and collections.
+    pub fn set_length_prefix(&mut self, length_prefix: LengthPrefix) -> &mut Self {
+        self.length_prefix = length_prefix;
+
+        self
+    }
+
+    /// Sets whether maps and sets with duplicate keys are rejected, see [`ByteBuffer::set_canonical`].
+    pub fn set_canonical(&mut self, canonical: bool) -> &mut Self {
+        self.canonical = canonical;
+
+        self
+    }
+
+    /// Sets the [`DecodeLimits`] enforced while reading, see [`ByteBuffer::set_decode_limits`].
+    pub fn set_decode_limits(&mut self, decode_limits: DecodeLimits) -> &mut Self {
+        self.decode_limits = decode_limits.reset();
+
+        self
+    }
+
+    /// Returns a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Returns a mutable reference to the underlying reader, reading from it directly skips bytes of the stream.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Returns the underlying reader, positioned after the last byte that was read.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: io::Read> Source for IoSource<R> {
+    #[inline]
+    fn read_slice(&mut self, size: usize) -> Result<&[u8]> {
+        self.scratch.clear();
+
+        let read = (&mut self.reader)
+            .take(size as u64)
+            .read_to_end(&mut self.scratch)
+            .map_err(ByteBufferError::from)?;
+
+        self.offset += read;
+
+        if read < size {
+            return Err(ByteBufferError::ReadOutOfBounds {
+                length: read,
+                start: 0,
+                end: size,
+            });
+        }
+
+        Ok(&self.scratch)
+    }
+
+    #[inline]
+    fn offset(&self) -> Option<usize> {
+        Some(self.offset)
+    }
+
+    #[inline]
+    fn length_prefix(&self) -> LengthPrefix {
+        self.length_prefix
+    }
+
+    #[inline]
+    fn canonical(&self) -> bool {
+        self.canonical
+    }
+
+    #[inline]
+    fn decode_limits_mut(&mut self) -> Option<&mut DecodeLimits> {
+        Some(&mut self.decode_limits)
+    }
+}
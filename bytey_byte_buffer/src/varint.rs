@@ -0,0 +1,176 @@
+use crate::{
+    error::{ByteBufferError, Result},
+    stream::Sink,
+};
+
+/// A [`u32`] that is stored in the [`ByteBuffer`](crate::byte_buffer::ByteBuffer) as an unsigned LEB128 variable length integer.
+///
+/// Values below 128 take a single byte, the largest values take 5 bytes.
+/// Since every byte is written separately the byte order of the read and write methods is ignored.
+///
+/// # Examples
+/// ```
+/// use bytey_byte_buffer::{byte_buffer::ByteBuffer, varint::VarU32};
+///
+/// let mut buffer = ByteBuffer::new().unwrap();
+///
+/// buffer.write(VarU32(300)).unwrap();
+/// assert_eq!(buffer.length(), 2);
+///
+/// buffer.move_cursor(0).unwrap();
+/// assert_eq!(buffer.read::<VarU32>().unwrap(), VarU32(300));
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct VarU32(pub u32);
+
+impl From<u32> for VarU32 {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<VarU32> for u32 {
+    fn from(value: VarU32) -> Self {
+        value.0
+    }
+}
+
+/// A [`u64`] that is stored in the [`ByteBuffer`](crate::byte_buffer::ByteBuffer) as an unsigned LEB128 variable length integer.
+///
+/// Values below 128 take a single byte, the largest values take 10 bytes.
+/// See [`VarU32`] for the byte order.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct VarU64(pub u64);
+
+impl From<u64> for VarU64 {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<VarU64> for u64 {
+    fn from(value: VarU64) -> Self {
+        value.0
+    }
+}
+
+/// An [`i32`] that is stored in the [`ByteBuffer`](crate::byte_buffer::ByteBuffer) as a zig-zag encoded LEB128 variable length integer.
+///
+/// Zig-zag maps small negative values to small unsigned values, so -1 takes a single byte like 1 does.
+/// This matches the `sint32` type of protobuf.
+///
+/// # Examples
+/// ```
+/// use bytey_byte_buffer::{byte_buffer::ByteBuffer, varint::VarI32};
+///
+/// let mut buffer = ByteBuffer::new().unwrap();
+///
+/// buffer.write(VarI32(-1)).unwrap();
+/// assert_eq!(buffer.length(), 1);
+///
+/// buffer.move_cursor(0).unwrap();
+/// assert_eq!(buffer.read::<VarI32>().unwrap(), VarI32(-1));
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct VarI32(pub i32);
+
+impl From<i32> for VarI32 {
+    fn from(value: i32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<VarI32> for i32 {
+    fn from(value: VarI32) -> Self {
+        value.0
+    }
+}
+
+/// An [`i64`] that is stored in the [`ByteBuffer`](crate::byte_buffer::ByteBuffer) as a zig-zag encoded LEB128 variable length integer.
+///
+/// See [`VarI32`] for the encoding, this matches the `sint64` type of protobuf.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct VarI64(pub i64);
+
+impl From<i64> for VarI64 {
+    fn from(value: i64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<VarI64> for i64 {
+    fn from(value: VarI64) -> Self {
+        value.0
+    }
+}
+
+/// Maps signed values to unsigned values so that values close to 0 stay small: 0, -1, 1, -2 become 0, 1, 2, 3.
+pub(crate) fn zigzag_encode(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+/// The inverse of [`zigzag_encode`].
+pub(crate) fn zigzag_decode(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+/// Writes the value as an unsigned LEB128 integer.
+pub(crate) fn write_unsigned<S: Sink>(buffer: &mut S, mut value: u64) -> Result<()> {
+    let mut bytes = [0u8; 10];
+    let mut len = 0;
+
+    loop {
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+
+        if value == 0 {
+            bytes[len] = byte;
+            len += 1;
+            break;
+        }
+
+        bytes[len] = byte | 0x80;
+        len += 1;
+    }
+
+    buffer.write_slice(&bytes[..len])?;
+
+    Ok(())
+}
+
+/// Reads an unsigned LEB128 integer that has to fit in the given amount of bits.
+///
+/// # Errors
+/// - [`ByteBufferError::VarIntOverflow`] is returned if the value does not fit in `bits`.
+/// - [`ByteBufferError::VarIntOverlong`] is returned if the value was not encoded with the least amount of bytes.
+///
+/// `next_byte` reads the next byte from the buffer.
+pub(crate) fn read_unsigned(
+    mut next_byte: impl FnMut() -> Result<u8>,
+    bits: u32,
+    type_name: &'static str,
+) -> Result<u64> {
+    let mut value: u64 = 0;
+    let mut shift: u32 = 0;
+
+    loop {
+        let byte = next_byte()?;
+        let payload = u64::from(byte & 0x7F);
+
+        if shift >= bits || (bits - shift < 7 && payload >> (bits - shift) != 0) {
+            return Err(ByteBufferError::VarIntOverflow { type_name });
+        }
+
+        value |= payload << shift;
+
+        if byte & 0x80 == 0 {
+            if byte == 0 && shift > 0 {
+                return Err(ByteBufferError::VarIntOverlong { type_name });
+            }
+
+            return Ok(value);
+        }
+
+        shift += 7;
+    }
+}
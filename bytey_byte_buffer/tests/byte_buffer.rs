@@ -111,10 +111,8 @@ fn test_expand_usize_overflow() {
 fn test_shrink_to_fit_min() {
     let mut buffer = ByteBuffer::new().unwrap();
 
-    assert_eq!(
-        buffer.shrink_to_fit().err().unwrap(),
-        ByteBufferError::MinCapacity
-    );
+    // shrink_to_fit clamps the capacity to MIN_SIZE instead of returning MinCapacity, unlike shrink_to.
+    assert!(buffer.shrink_to_fit().is_ok());
     assert_eq!(buffer.capacity(), ByteBuffer::MIN_SIZE);
 }
 
@@ -228,3 +226,106 @@ fn test_read_to_buffer() {
     assert_eq!(buffer.cursor(), 8);
     assert_eq!(new_buffer.capacity(), buffer.capacity());
 }
+
+#[test]
+fn test_try_read_restores_cursor() {
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    let _ = buffer.write(1u8);
+    let _ = buffer.write_be(2u16);
+    let _ = buffer.write_be(3u16);
+    let _ = buffer.move_cursor(1);
+
+    assert!(buffer.try_read_be::<(u16, u16, u32)>().is_err());
+    assert_eq!(buffer.cursor(), 1);
+
+    assert!(buffer.read_be::<(u16, u16, u32)>().is_err());
+    assert_eq!(buffer.cursor(), 5);
+
+    let _ = buffer.move_cursor(1);
+    assert_eq!(buffer.try_read_be::<(u16, u16)>().unwrap(), (2, 3));
+    assert_eq!(buffer.cursor(), 5);
+}
+
+#[test]
+fn test_transaction() {
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    let _ = buffer.write_slice(&[1, 2, 3]);
+    let _ = buffer.move_cursor(0);
+
+    let result = buffer.transaction(|b| {
+        let a = b.read::<u8>()?;
+
+        if a != 2 {
+            return Err(ByteBufferError::OtherError {
+                error: String::from("unexpected value"),
+            });
+        }
+
+        Ok(a)
+    });
+
+    assert!(result.is_err());
+    assert_eq!(buffer.cursor(), 0);
+
+    let result = buffer.transaction(|b| Ok((b.read::<u8>()?, b.read::<u8>()?)));
+
+    assert_eq!(result.unwrap(), (1, 2));
+    assert_eq!(buffer.cursor(), 2);
+}
+
+#[test]
+fn test_peek() {
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    let _ = buffer.write_le(0x0102u16);
+    let _ = buffer.move_cursor(0);
+
+    assert_eq!(buffer.peek_le::<u16>().unwrap(), 0x0102);
+    assert_eq!(buffer.peek_be::<u16>().unwrap(), 0x0201);
+    assert_eq!(buffer.peek::<u8>().unwrap(), 2);
+    assert_eq!(buffer.cursor(), 0);
+
+    assert!(buffer.peek::<u32>().is_err());
+    assert_eq!(buffer.cursor(), 0);
+}
+
+#[test]
+fn test_vec_conversions_reuse_allocation() {
+    let mut vec = Vec::with_capacity(32);
+
+    vec.extend_from_slice(&[1, 2, 3]);
+
+    let pointer = vec.as_ptr();
+    let mut buffer = ByteBuffer::from(vec);
+
+    assert_eq!(buffer.capacity(), 32);
+    assert_eq!(buffer.length(), 3);
+    assert_eq!(buffer.cursor(), 0);
+
+    let _ = buffer.write_slice(&[4, 5]);
+    let vec: Vec<u8> = buffer.into();
+
+    assert_eq!(vec, [4, 5, 3]);
+    assert_eq!(vec.as_ptr(), pointer);
+    assert_eq!(vec.capacity(), 32);
+
+    let mut buffer = ByteBuffer::from(Vec::new());
+
+    assert_eq!(buffer.capacity(), ByteBuffer::MIN_SIZE);
+    assert!(buffer.write(1u64).is_ok());
+}
+
+#[test]
+fn test_as_bytes() {
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    let _ = buffer.write_slice(&[1, 2, 3]);
+    let _ = buffer.move_cursor(1);
+
+    assert_eq!(buffer.as_bytes(), [1, 2, 3]);
+    assert_eq!(buffer.as_ref(), [1, 2, 3]);
+    assert_eq!(&buffer[1..], [2, 3]);
+    assert_eq!(buffer.cursor(), 1);
+}
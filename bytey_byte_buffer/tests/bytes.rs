@@ -0,0 +1,123 @@
+#![cfg(feature = "bytes")]
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use bytey_byte_buffer::{
+    byte_buffer::ByteBuffer,
+    bytes::{BufSink, BufSource},
+    error::ByteBufferError,
+    stream::{Sink, Source},
+};
+
+#[test]
+fn test_bytes_conversions_reuse_allocation() {
+    let mut buffer = ByteBuffer::with_capacity(64).unwrap();
+
+    buffer.write_slice(&[1, 2, 3]).unwrap();
+
+    let pointer = buffer.as_slice().as_ptr();
+    let bytes = buffer.into_bytes();
+
+    assert_eq!(&bytes[..], [1, 2, 3]);
+    assert_eq!(bytes.as_ptr(), pointer);
+
+    let mut bytes_mut = BytesMut::with_capacity(64);
+
+    bytes_mut.put_slice(&[4, 5]);
+
+    let pointer = bytes_mut.as_ptr();
+    let mut buffer = ByteBuffer::from_bytes_mut(bytes_mut);
+
+    assert_eq!(buffer.cursor(), 0);
+    assert_eq!(buffer.length(), 2);
+    assert_eq!(buffer.read_slice(2).unwrap().as_ptr(), pointer);
+
+    let mut buffer = ByteBuffer::from(Bytes::from_static(b"abc"));
+
+    assert_eq!(buffer.read_slice(3).unwrap(), b"abc");
+}
+
+#[test]
+fn test_buf_impls() {
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.put_u32(0x01020304);
+    buffer.put_slice(&[5; 20]);
+    buffer.put_u16_le(6);
+
+    assert_eq!(buffer.length(), 26);
+    assert_eq!(buffer.cursor(), 26);
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(Buf::remaining(&buffer), 26);
+    assert_eq!(buffer.get_u32(), 0x01020304);
+
+    buffer.advance(20);
+
+    assert_eq!(buffer.get_u16_le(), 6);
+    assert!(!buffer.has_remaining());
+}
+
+#[test]
+fn test_buf_source() {
+    let mut bytes = BytesMut::new();
+
+    bytes.put_u16(1);
+    bytes.put_u64(2);
+    bytes.put_slice(b"hi");
+
+    // Splits the data over multiple chunks.
+    let chained = bytes.split_to(5).freeze().chain(bytes.freeze());
+    let mut source = BufSource::new(chained);
+
+    assert_eq!(source.read_be::<u16>().unwrap(), 1);
+    assert_eq!(source.read_be::<String>().unwrap(), "hi");
+    assert_eq!(
+        source.read::<u8>(),
+        Err(ByteBufferError::ReadOutOfBounds {
+            length: 0,
+            start: 0,
+            end: 1
+        })
+    );
+    assert!(!source.into_inner().has_remaining());
+}
+
+#[test]
+fn test_buf_sink() {
+    let mut sink = BufSink::new(BytesMut::new());
+
+    sink.write_be(vec![1u16, 2]).unwrap();
+
+    assert_eq!(&sink.into_inner()[..], [0, 0, 0, 0, 0, 0, 0, 2, 0, 1, 0, 2]);
+
+    let mut array = [0u8; 2];
+    let mut sink = BufSink::new(&mut array[..]);
+
+    assert_eq!(sink.write(1u32), Err(ByteBufferError::MaxCapacity));
+}
+
+#[test]
+fn test_buf_canonical() {
+    let set: std::collections::HashSet<u16> = (0..32).collect();
+    let mut buffer = ByteBuffer::new().unwrap();
+    let mut sink = BufSink::new(BytesMut::new());
+
+    buffer.set_canonical(true).write(&set).unwrap();
+    sink.set_canonical(true);
+    sink.write(&set).unwrap();
+
+    assert_eq!(&sink.get_ref()[..], buffer.as_bytes());
+
+    let mut source = BufSource::new(&[2, 0, 0, 0, 0, 0, 0, 0, 1, 1][..]);
+
+    source.set_canonical(true);
+
+    assert_eq!(
+        source
+            .read::<std::collections::BTreeSet<u8>>()
+            .unwrap_err()
+            .root(),
+        &ByteBufferError::DuplicateKey
+    );
+}
@@ -0,0 +1,226 @@
+use bytey_byte_buffer::{
+    byte_buffer::ByteBuffer,
+    decode_limits::DecodeLimits,
+    error::ByteBufferError,
+    fixed_buffer::ArrayByteBuffer,
+    length_prefix::LengthPrefix,
+    stream::{IoSink, IoSource, Sink, Source},
+};
+use std::{
+    collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque},
+    hash::{BuildHasherDefault, DefaultHasher},
+};
+
+#[test]
+fn test_sequences() {
+    let deque = VecDeque::from([1u16, 2, 3]);
+    let list = LinkedList::from([String::from("a"), String::from("b")]);
+    let heap = BinaryHeap::from([3u8, 1, 2]);
+
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write(&deque).unwrap();
+    buffer.write_le(&list).unwrap();
+    buffer.write_be(&heap).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<VecDeque<u16>>().unwrap(), deque);
+    assert_eq!(buffer.read_le::<LinkedList<String>>().unwrap(), list);
+    assert_eq!(
+        buffer
+            .read_be::<BinaryHeap<u8>>()
+            .unwrap()
+            .into_sorted_vec(),
+        [1, 2, 3]
+    );
+}
+
+#[test]
+fn test_sequence_format() {
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.set_length_prefix(LengthPrefix::U8);
+    buffer.write_be(VecDeque::from([1u16, 2])).unwrap();
+    buffer.write_be(BTreeSet::from([2u16, 1])).unwrap();
+
+    // Same as a Vec, the set is written in order.
+    assert_eq!(buffer.as_bytes(), [2, 0, 1, 0, 2, 2, 0, 1, 0, 2]);
+}
+
+#[test]
+fn test_maps() {
+    let btree = BTreeMap::from([(1u8, String::from("one")), (2, String::from("two"))]);
+    let hash = HashMap::from([(String::from("a"), 1u32), (String::from("b"), 2)]);
+    let set = HashSet::from([1u64, 2, 3]);
+
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write(&btree).unwrap();
+    buffer.write_le(&hash).unwrap();
+    buffer.write_be(&set).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<BTreeMap<u8, String>>().unwrap(), btree);
+    assert_eq!(buffer.read_le::<HashMap<String, u32>>().unwrap(), hash);
+    assert_eq!(buffer.read_be::<HashSet<u64>>().unwrap(), set);
+}
+
+#[test]
+fn test_map_hasher() {
+    type Map = HashMap<u8, u8, BuildHasherDefault<DefaultHasher>>;
+
+    let map: Map = [(1, 2), (3, 4)].into_iter().collect();
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write(&map).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<Map>().unwrap(), map);
+}
+
+#[test]
+fn test_map_limits_and_context() {
+    let mut buffer = ByteBuffer::new().unwrap();
+    let mut limits = DecodeLimits::new();
+
+    limits.set_max_collection_length(2);
+    buffer.set_decode_limits(limits);
+
+    buffer
+        .write(BTreeMap::from([(1u8, 'a'), (2, 'b'), (3, 'c')]))
+        .unwrap();
+    buffer
+        .write(BTreeMap::from([(1u8, 2u32), (2, 0xD800)]))
+        .unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        buffer.read::<BTreeMap<u8, char>>(),
+        Err(ByteBufferError::CollectionTooLong { length: 3, max: 2 })
+    );
+
+    // Skip the rest of the first map.
+    buffer.move_cursor(8 + 3 * 5).unwrap();
+
+    let error = buffer.read::<BTreeMap<u8, char>>().unwrap_err();
+
+    assert_eq!(error.root(), &ByteBufferError::NotAChar);
+    assert_eq!(error.context().unwrap().path().to_string(), "[1].value");
+}
+
+#[test]
+fn test_canonical_is_deterministic() {
+    let mut encodings = Vec::new();
+
+    // Every map gets its own random hasher, so iteration order differs between them.
+    for _ in 0..8 {
+        let map: HashMap<String, HashSet<u16>> = (0..16u16)
+            .map(|i| (i.to_string(), (i..i + 4).collect()))
+            .collect();
+        let mut buffer = ByteBuffer::new().unwrap();
+
+        buffer.set_canonical(true);
+        buffer.write_be(&map).unwrap();
+        buffer.move_cursor(0).unwrap();
+
+        assert_eq!(
+            buffer.read_be::<HashMap<String, HashSet<u16>>>().unwrap(),
+            map
+        );
+
+        encodings.push(buffer.as_bytes().to_vec());
+    }
+
+    assert!(encodings.windows(2).all(|pair| pair[0] == pair[1]));
+}
+
+#[test]
+fn test_canonical_format() {
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer
+        .set_length_prefix(LengthPrefix::U8)
+        .set_canonical(true);
+    buffer
+        .write_le(BTreeMap::from([(1u16, 'a'), (256, 'b')]))
+        .unwrap();
+    buffer.write(BinaryHeap::from([1u8, 3, 2])).unwrap();
+
+    // 256 is encoded as [0, 1] so it sorts before 1 in little endian.
+    assert_eq!(
+        buffer.as_bytes(),
+        [2, 0, 1, b'b', 0, 0, 0, 1, 0, b'a', 0, 0, 0, 3, 1, 2, 3]
+    );
+}
+
+#[test]
+fn test_canonical_duplicate_key() {
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.set_length_prefix(LengthPrefix::U8);
+    buffer.write_slice(&[3, 1, 10, 2, 20, 1, 30]).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    // Without canonical mode the last value wins.
+    assert_eq!(
+        buffer.read::<BTreeMap<u8, u8>>().unwrap(),
+        BTreeMap::from([(1, 30), (2, 20)])
+    );
+
+    buffer.set_canonical(true);
+    buffer.move_cursor(0).unwrap();
+
+    let error = buffer.read::<HashMap<u8, u8>>().unwrap_err();
+
+    assert_eq!(error.root(), &ByteBufferError::DuplicateKey);
+    assert_eq!(error.context().unwrap().path().to_string(), "[2]");
+
+    // Binary heaps hold duplicates.
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<BinaryHeap<u8>>().unwrap().len(), 3);
+}
+
+#[test]
+fn test_canonical_sinks_and_sources() {
+    let set: HashSet<u16> = (0..32).collect();
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.set_canonical(true);
+    buffer.write(&set).unwrap();
+
+    // Streaming and fixed capacity buffers write the same bytes as a ByteBuffer.
+    let mut sink = IoSink::new(Vec::new());
+    let mut array = ArrayByteBuffer::<128>::new();
+
+    sink.set_canonical(true);
+    sink.write(&set).unwrap();
+    array.set_canonical(true);
+    array.write(&set).unwrap();
+
+    assert_eq!(sink.get_ref().as_slice(), buffer.as_bytes());
+    assert_eq!(array.as_bytes(), buffer.as_bytes());
+
+    // And reject duplicate keys when reading.
+    let bytes = [2, 0, 0, 0, 0, 0, 0, 0, 1, 1];
+    let mut source = IoSource::new(&bytes[..]);
+
+    source.set_canonical(true);
+
+    assert_eq!(
+        source.read::<HashSet<u8>>().unwrap_err().root(),
+        &ByteBufferError::DuplicateKey
+    );
+
+    array.clear().write_slice(&bytes).unwrap();
+    array.move_cursor(0).unwrap();
+
+    assert_eq!(
+        array.reader().read::<BTreeSet<u8>>().unwrap_err().root(),
+        &ByteBufferError::DuplicateKey
+    );
+    assert_eq!(
+        array.read::<BTreeSet<u8>>().unwrap_err().root(),
+        &ByteBufferError::DuplicateKey
+    );
+}
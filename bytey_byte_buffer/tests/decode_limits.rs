@@ -0,0 +1,161 @@
+use bytey_byte_buffer::{
+    byte_buffer::ByteBuffer,
+    decode_limits::DecodeLimits,
+    error::ByteBufferError,
+    stream::{IoSource, Source},
+};
+
+#[test]
+fn test_forged_length_does_not_allocate() {
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    // A 9 byte packet claiming to hold u64::MAX / 2 elements.
+    buffer.write(usize::MAX / 2).unwrap();
+    buffer.write(1u8).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert!(matches!(
+        buffer.read::<Vec<u64>>().unwrap_err().root(),
+        ByteBufferError::ReadOutOfBounds { .. }
+    ));
+}
+
+#[test]
+fn test_collection_limit() {
+    let mut buffer = ByteBuffer::new().unwrap();
+    let mut limits = DecodeLimits::new();
+
+    limits.set_max_collection_length(3);
+    buffer.set_decode_limits(limits);
+    assert_eq!(buffer.decode_limits(), limits);
+
+    buffer.write(vec![1u16, 2, 3]).unwrap();
+    buffer.write(vec![1u16, 2, 3, 4]).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<Vec<u16>>().unwrap(), [1, 2, 3]);
+    assert_eq!(
+        buffer.read::<Vec<u16>>(),
+        Err(ByteBufferError::CollectionTooLong { length: 4, max: 3 })
+    );
+}
+
+#[test]
+fn test_string_limit() {
+    let mut buffer = ByteBuffer::new().unwrap();
+    let mut limits = DecodeLimits::new();
+
+    limits.set_max_string_length(5);
+    buffer.set_decode_limits(limits);
+
+    buffer.write("bytey").unwrap();
+    buffer.write("bytey!").unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    // The reader of the buffer uses the same limits.
+    let mut reader = buffer.reader();
+
+    assert_eq!(reader.read::<String>().unwrap(), "bytey");
+    assert_eq!(
+        reader.read::<String>(),
+        Err(ByteBufferError::StringTooLong { length: 6, max: 5 })
+    );
+}
+
+#[test]
+fn test_allocation_limit() {
+    let mut buffer = ByteBuffer::new().unwrap();
+    let mut limits = DecodeLimits::new();
+
+    limits.set_max_allocation(100);
+    buffer.set_decode_limits(limits);
+
+    // 2 vectors of 24 bytes, followed by 24 bytes of elements for each of them.
+    let value = vec![vec![0u32; 6], vec![0u32; 6]];
+
+    buffer.write(&value).unwrap();
+    buffer.write(&value).unwrap();
+    buffer.write(vec![vec![0u32; 7], vec![0u32; 8]]).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    // Every value read starts counting from 0.
+    assert_eq!(buffer.read::<Vec<Vec<u32>>>().unwrap(), value);
+    assert_eq!(buffer.read::<Vec<Vec<u32>>>().unwrap(), value);
+    assert_eq!(
+        buffer.read::<Vec<Vec<u32>>>().unwrap_err().root(),
+        &ByteBufferError::AllocationLimit {
+            size: 108,
+            max: 100
+        }
+    );
+}
+
+#[test]
+fn test_depth_limit() {
+    let mut buffer = ByteBuffer::new().unwrap();
+    let mut limits = DecodeLimits::new();
+
+    limits.set_max_depth(2);
+    buffer.set_decode_limits(limits);
+
+    buffer.write(vec![vec![1u8]]).unwrap();
+    buffer.write(vec![vec![vec![1u8]]]).unwrap();
+    buffer.write(Box::new(Box::new(Box::new(1u8)))).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<Vec<Vec<u8>>>().unwrap(), [[1]]);
+    assert_eq!(
+        buffer.read::<Vec<Vec<Vec<u8>>>>().unwrap_err().root(),
+        &ByteBufferError::DepthLimit { max: 2 }
+    );
+    assert_eq!(
+        buffer.read::<Box<Box<Box<u8>>>>(),
+        Err(ByteBufferError::DepthLimit { max: 2 })
+    );
+}
+
+#[test]
+fn test_io_source_limits() {
+    let mut bytes = ByteBuffer::new().unwrap();
+
+    bytes.write(String::from("bytey")).unwrap();
+
+    let mut source = IoSource::new(bytes.as_bytes());
+    let mut limits = DecodeLimits::new();
+
+    limits.set_max_string_length(4);
+    source.set_decode_limits(limits);
+
+    assert_eq!(
+        source.read::<String>(),
+        Err(ByteBufferError::StringTooLong { length: 5, max: 4 })
+    );
+}
+
+#[test]
+fn test_max_length_prefix() {
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    // An 11 byte packet whose length wraps the end of the read around.
+    buffer.write(u64::MAX).unwrap();
+    buffer.write_slice(b"abc").unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        buffer.read::<String>(),
+        Err(ByteBufferError::ReadOutOfBounds {
+            length: 11,
+            start: 8,
+            end: usize::MAX,
+        })
+    );
+
+    assert!(buffer.slice_from(8, usize::MAX).is_err());
+
+    buffer.move_cursor(0).unwrap();
+
+    assert!(matches!(
+        buffer.reader().read_ref::<&str>(),
+        Err(ByteBufferError::ReadOutOfBounds { end: usize::MAX, .. })
+    ));
+}
@@ -0,0 +1,157 @@
+use bytey_byte_buffer::{
+    byte_buffer::ByteBuffer,
+    error::{ByteBufferError, PathSegment},
+    stream::{IoSource, Source},
+};
+use std::collections::{BTreeMap, HashMap};
+
+#[test]
+fn test_invalid_tag() {
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write(5u8).unwrap();
+    buffer.write(3u8).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        buffer.read::<Option<u8>>(),
+        Err(ByteBufferError::InvalidTag {
+            type_name: "Option",
+            tag: 5
+        })
+    );
+    assert_eq!(
+        buffer.read::<Result<u8, u8>>(),
+        Err(ByteBufferError::InvalidTag {
+            type_name: "Result",
+            tag: 3
+        })
+    );
+}
+
+#[test]
+fn test_array_length_mismatch() {
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write([1u16, 2, 3]).unwrap();
+    buffer.write([0u16; 0]).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        buffer.read::<[u16; 4]>(),
+        Err(ByteBufferError::ArrayLengthMismatch {
+            expected: 4,
+            found: 3
+        })
+    );
+
+    buffer.move_cursor(0).unwrap();
+    assert_eq!(buffer.read::<[u16; 3]>().unwrap(), [1, 2, 3]);
+    assert_eq!(buffer.read::<[u16; 0]>().unwrap(), []);
+}
+
+#[test]
+fn test_element_path() {
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write(vec![(1u8, 'a'), (2, 'b')]).unwrap();
+
+    // Corrupt the char of the second element.
+    let length = buffer.length();
+
+    buffer.truncate(length - 4).unwrap();
+    buffer.move_cursor_to_end().write(0xD800u32).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    let error = buffer.read::<Vec<(u8, char)>>().unwrap_err();
+    let context = error.context().unwrap();
+
+    assert_eq!(error.root(), &ByteBufferError::NotAChar);
+    assert_eq!(
+        context.path().segments(),
+        [
+            PathSegment::Index(1),
+            PathSegment::Field {
+                type_name: "tuple",
+                field: "1"
+            }
+        ]
+    );
+    assert_eq!(context.path().to_string(), "[1].1");
+    assert_eq!(context.offset(), Some(length));
+}
+
+#[test]
+fn test_io_source_offset() {
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write(vec![Some(1u8), Some(2)]).unwrap();
+
+    // Replace the tag of the second option, after the length and the first option.
+    let mut bytes = buffer.as_bytes().to_vec();
+
+    bytes[8 + 2] = 9;
+
+    let mut source = IoSource::new(&bytes[..]);
+    let error = source.read::<Vec<Option<u8>>>().unwrap_err();
+    let context = error.context().unwrap();
+
+    assert_eq!(
+        error.root(),
+        &ByteBufferError::InvalidTag {
+            type_name: "Option",
+            tag: 9
+        }
+    );
+    assert_eq!(context.path().to_string(), "[1]");
+    assert_eq!(context.offset(), Some(11));
+}
+
+#[test]
+fn test_map_entry_path() {
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer
+        .write(BTreeMap::from([(1u8, (0u8, 'a')), (2, (0, 'b'))]))
+        .unwrap();
+
+    // Corrupt the char of the second value.
+    let length = buffer.length();
+
+    buffer.truncate(length - 4).unwrap();
+    buffer.move_cursor_to_end().write(0xD800u32).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    let error = buffer.read::<BTreeMap<u8, (u8, char)>>().unwrap_err();
+    let context = error.context().unwrap();
+
+    assert_eq!(error.root(), &ByteBufferError::NotAChar);
+    assert_eq!(
+        context.path().segments(),
+        [
+            PathSegment::Index(1),
+            PathSegment::Field {
+                type_name: "map entry",
+                field: "value"
+            },
+            PathSegment::Field {
+                type_name: "tuple",
+                field: "1"
+            }
+        ]
+    );
+    assert_eq!(context.path().to_string(), "[1].value.1");
+
+    // Keys are named as well.
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write(1u64).unwrap();
+    buffer.write(0xD800u32).unwrap();
+    buffer.write(1u8).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    let error = buffer.read::<HashMap<char, u8>>().unwrap_err();
+
+    assert_eq!(error.root(), &ByteBufferError::NotAChar);
+    assert_eq!(error.context().unwrap().path().to_string(), "[0].key");
+}
@@ -0,0 +1,129 @@
+use bytey_byte_buffer::{
+    byte_buffer::ByteBuffer,
+    error::{ByteBufferError, Result},
+    fixed_buffer::{ArrayByteBuffer, SliceBuffer},
+    length_prefix::LengthPrefix,
+    stream::{Sink, Source},
+};
+
+fn round_trip<B: Sink + Source>(buffer: &mut B) -> Result<()> {
+    buffer.write_be(0x0102u16)?;
+    buffer.write("abc")?;
+    buffer.write(Some(7u8))?;
+
+    Ok(())
+}
+
+#[test]
+fn test_fixed_buffers_match_byte_buffer() {
+    let mut buffer = ByteBuffer::new().unwrap();
+    let mut array = ArrayByteBuffer::<32>::new();
+    let mut bytes = [0u8; 32];
+    let mut slice = SliceBuffer::new(&mut bytes);
+
+    round_trip(&mut buffer).unwrap();
+    round_trip(&mut array).unwrap();
+    round_trip(&mut slice).unwrap();
+
+    assert_eq!(array.as_bytes(), buffer.as_bytes());
+    assert_eq!(slice.as_bytes(), buffer.as_bytes());
+
+    array.move_cursor(0).unwrap();
+
+    assert_eq!(array.read_be::<u16>().unwrap(), 0x0102);
+    assert_eq!(array.read::<String>().unwrap(), "abc");
+    assert_eq!(array.read::<Option<u8>>().unwrap(), Some(7));
+    assert!(matches!(
+        array.read::<u8>(),
+        Err(ByteBufferError::ReadOutOfBounds { .. })
+    ));
+}
+
+#[test]
+fn test_fixed_buffer_max_capacity() {
+    let mut array = ArrayByteBuffer::<4>::new();
+
+    array.write(1u16).unwrap();
+
+    assert_eq!(array.write(2u32).unwrap_err(), ByteBufferError::MaxCapacity);
+    assert_eq!(array.length(), 2);
+    assert_eq!(array.cursor(), 2);
+
+    array.write(3u16).unwrap();
+
+    assert_eq!(array.length(), array.capacity());
+    assert_eq!(array.write(0u8).unwrap_err(), ByteBufferError::MaxCapacity);
+
+    let mut bytes = [0u8; 3];
+    let mut slice = SliceBuffer::new(&mut bytes);
+
+    slice.set_length_prefix(LengthPrefix::U8);
+
+    assert_eq!(
+        slice.write("abc").unwrap_err(),
+        ByteBufferError::MaxCapacity
+    );
+
+    slice.clear().write("ab").unwrap();
+
+    assert_eq!(slice.into_inner(), [2, b'a', b'b']);
+}
+
+#[test]
+fn test_fixed_buffer_reader() {
+    let mut array = ArrayByteBuffer::<16>::new();
+
+    array.write("hello").unwrap();
+    array.move_cursor(0).unwrap();
+
+    let text: &str = array.reader().read_ref().unwrap();
+
+    assert_eq!(text, "hello");
+}
+
+#[test]
+fn test_slice_buffer_from_filled() {
+    let mut bytes = [0u8; 32];
+    let received = {
+        let mut buffer = ByteBuffer::new().unwrap();
+
+        buffer.write("bytey").unwrap();
+        buffer.write_be(7u16).unwrap();
+        buffer.as_bytes().to_vec()
+    };
+
+    bytes[..received.len()].copy_from_slice(&received);
+
+    let mut slice = SliceBuffer::from_filled(&mut bytes, received.len()).unwrap();
+
+    assert_eq!(slice.read::<String>().unwrap(), "bytey");
+    assert_eq!(slice.read_be::<u16>().unwrap(), 7);
+    assert!(slice.read::<u8>().is_err());
+
+    // New values are written after the received bytes.
+    slice.write(1u8).unwrap();
+    assert_eq!(slice.length(), received.len() + 1);
+
+    assert_eq!(
+        SliceBuffer::from_filled(&mut [0u8; 4], 5).unwrap_err(),
+        ByteBufferError::LengthOutOfBounds { current: 4, new: 5 }
+    );
+}
+
+#[test]
+fn test_fixed_buffer_forged_length() {
+    let mut array = ArrayByteBuffer::<16>::new();
+
+    array.write(u64::MAX).unwrap();
+    array.write_slice(b"abc").unwrap();
+    array.move_cursor(0).unwrap();
+
+    assert_eq!(
+        array.read::<String>().unwrap_err().root(),
+        &ByteBufferError::ReadOutOfBounds {
+            length: 11,
+            start: 8,
+            end: usize::MAX,
+        }
+    );
+}
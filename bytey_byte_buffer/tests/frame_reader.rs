@@ -0,0 +1,162 @@
+use bytey_byte_buffer::{
+    decode_limits::DecodeLimits, error::ByteBufferError, frame_reader::FrameReader,
+    length_prefix::LengthPrefix,
+};
+
+#[test]
+fn test_frame_reader_chunks() {
+    let mut reader = FrameReader::new().unwrap();
+    let data = [0, 0, 0, 2, 1, 2, 0, 0, 0, 0, 0, 0, 0, 1, 3];
+    let mut frames: Vec<Vec<u8>> = Vec::new();
+
+    for byte in data {
+        reader.write_slice(&[byte]).unwrap();
+
+        loop {
+            match reader.next_frame() {
+                Ok(frame) => frames.push(frame.to_vec()),
+                Err(ByteBufferError::Incomplete { .. }) => break,
+                Err(error) => panic!("{error}"),
+            }
+        }
+    }
+
+    assert_eq!(frames, [vec![1, 2], vec![], vec![3]]);
+    assert_eq!(reader.buffered(), 0);
+}
+
+#[test]
+fn test_frame_reader_incomplete() {
+    let mut reader = FrameReader::new().unwrap();
+
+    assert_eq!(
+        reader.next_frame(),
+        Err(ByteBufferError::Incomplete { needed: 4 })
+    );
+
+    reader.write_slice(&[0, 0, 1]).unwrap();
+    assert_eq!(
+        reader.next_frame(),
+        Err(ByteBufferError::Incomplete { needed: 1 })
+    );
+
+    reader.write_slice(&[0, 7]).unwrap();
+    assert_eq!(
+        reader.next_frame(),
+        Err(ByteBufferError::Incomplete { needed: 255 })
+    );
+    assert_eq!(reader.buffered(), 5);
+
+    let mut varint = FrameReader::new().unwrap();
+
+    varint.set_length_prefix(LengthPrefix::VarInt);
+    varint.write_slice(&[0x80]).unwrap();
+    assert_eq!(
+        varint.next_frame(),
+        Err(ByteBufferError::Incomplete { needed: 1 })
+    );
+
+    varint.write_slice(&[0x01]).unwrap();
+    assert_eq!(
+        varint.next_frame(),
+        Err(ByteBufferError::Incomplete { needed: 128 })
+    );
+}
+
+#[test]
+fn test_frame_reader_compacts() {
+    let mut reader = FrameReader::with_capacity(16).unwrap();
+
+    for i in 0..100u8 {
+        reader.write_slice(&[0, 0, 0, 4, i, i, i, i]).unwrap();
+        reader.write_slice(&[0, 0]).unwrap();
+
+        assert_eq!(reader.next_frame().unwrap(), [i; 4]);
+        assert!(reader.next_frame().is_err());
+
+        reader.write_slice(&[0, 1, i]).unwrap();
+
+        assert_eq!(reader.next_frame().unwrap(), [i]);
+    }
+
+    assert_eq!(reader.capacity(), 16);
+}
+
+#[test]
+fn test_frame_reader_read_frame() {
+    let mut reader = FrameReader::new().unwrap();
+
+    reader.write_slice(&[0, 0, 0, 2, 0, 7]).unwrap();
+    reader
+        .write_slice(&[0, 0, 0, 2, 1, 2, 0, 0, 0, 1, 9])
+        .unwrap();
+
+    assert_eq!(reader.read_frame::<u16>().unwrap(), 7);
+    assert_eq!(reader.read_frame::<u16>().unwrap(), 0x0102);
+    reader.write_slice(&[0, 0, 0, 1, 4]).unwrap();
+
+    assert!(matches!(
+        reader.read_frame::<u16>(),
+        Err(ByteBufferError::ReadOutOfBounds { .. })
+    ));
+    assert_eq!(reader.buffered(), 5);
+    assert_eq!(reader.next_frame().unwrap(), [4]);
+    assert_eq!(reader.buffered(), 0);
+}
+
+#[test]
+fn test_frame_reader_max_frame_length() {
+    let mut reader = FrameReader::new().unwrap();
+
+    reader.set_max_frame_length(8);
+    reader.write_slice(&[0, 0, 1, 0]).unwrap();
+
+    assert_eq!(
+        reader.next_frame(),
+        Err(ByteBufferError::LengthOverflow {
+            length: 256,
+            target: "the max frame length"
+        })
+    );
+}
+
+#[test]
+fn test_frame_reader_trailing_bytes() {
+    let mut reader = FrameReader::new().unwrap();
+
+    reader.write_slice(&[0, 0, 0, 3, 0, 7, 8]).unwrap();
+    reader.write_slice(&[0, 0, 0, 2, 0, 9]).unwrap();
+
+    assert_eq!(
+        reader.read_frame::<u16>(),
+        Err(ByteBufferError::TrailingBytes { remaining: 1 })
+    );
+
+    // The frame is popped, so the next one can still be read.
+    assert_eq!(reader.read_frame::<u16>().unwrap(), 9);
+    assert_eq!(reader.buffered(), 0);
+}
+
+#[test]
+fn test_frame_reader_decode_limits() {
+    let mut reader = FrameReader::new().unwrap();
+    let mut limits = DecodeLimits::new();
+
+    limits.set_max_collection_length(2);
+    reader.set_decode_limits(limits);
+    assert_eq!(reader.decode_limits(), limits);
+
+    reader
+        .write_slice(&[0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 2, 1, 2])
+        .unwrap();
+    reader
+        .write_slice(&[0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 3, 1, 2, 3])
+        .unwrap();
+
+    assert_eq!(reader.read_frame::<Vec<u8>>().unwrap(), [1, 2]);
+    assert_eq!(
+        reader.read_frame::<Vec<u8>>(),
+        Err(ByteBufferError::CollectionTooLong { length: 3, max: 2 })
+    );
+    assert_eq!(reader.buffered(), 0);
+}
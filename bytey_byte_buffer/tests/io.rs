@@ -0,0 +1,96 @@
+use bytey_byte_buffer::byte_buffer::ByteBuffer;
+use std::io::{self, BufRead, Read, Seek, SeekFrom, Write};
+
+#[test]
+fn test_io_write_read() {
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write_all(b"hello ").unwrap();
+    write!(buffer, "{}", 42).unwrap();
+    buffer.flush().unwrap();
+
+    assert_eq!(buffer.length(), 8);
+    assert_eq!(buffer.cursor(), 8);
+
+    let mut out = [0u8; 16];
+    assert_eq!(Read::read(&mut buffer, &mut out).unwrap(), 0);
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(Read::read(&mut buffer, &mut out).unwrap(), 8);
+    assert_eq!(&out[..8], b"hello 42");
+}
+
+#[test]
+fn test_io_copy() {
+    let mut source = ByteBuffer::new().unwrap();
+    let mut target = ByteBuffer::new().unwrap();
+    let data: Vec<u8> = (0..=255).collect();
+
+    source.write_slice(&data).unwrap();
+    source.move_cursor(0).unwrap();
+
+    assert_eq!(io::copy(&mut source, &mut target).unwrap(), 256);
+
+    target.move_cursor(0).unwrap();
+    assert_eq!(target.read_slice(256).unwrap(), &data[..]);
+}
+
+#[test]
+fn test_io_seek() {
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write_slice(&[0, 1, 2, 3, 4, 5]).unwrap();
+
+    assert_eq!(buffer.seek(SeekFrom::Start(2)).unwrap(), 2);
+    assert_eq!(buffer.seek(SeekFrom::Current(3)).unwrap(), 5);
+    assert_eq!(buffer.seek(SeekFrom::Current(-1)).unwrap(), 4);
+    assert_eq!(buffer.seek(SeekFrom::End(-6)).unwrap(), 0);
+    assert_eq!(buffer.seek(SeekFrom::End(0)).unwrap(), 6);
+
+    assert_eq!(
+        buffer.seek(SeekFrom::End(1)).unwrap_err().kind(),
+        io::ErrorKind::InvalidInput
+    );
+    assert_eq!(
+        buffer.seek(SeekFrom::Current(-7)).unwrap_err().kind(),
+        io::ErrorKind::InvalidInput
+    );
+    assert_eq!(
+        buffer.seek(SeekFrom::Start(u64::MAX)).unwrap_err().kind(),
+        io::ErrorKind::InvalidInput
+    );
+    assert_eq!(buffer.cursor(), 6);
+}
+
+#[test]
+fn test_io_buf_read() {
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write_slice(b"first\nsecond\n").unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.fill_buf().unwrap(), b"first\nsecond\n");
+    assert_eq!(buffer.cursor(), 0);
+
+    buffer.consume(6);
+
+    let lines: Vec<String> = buffer.lines().map(|line| line.unwrap()).collect();
+
+    assert_eq!(lines, ["second"]);
+}
+
+#[test]
+fn test_io_read_exact_eof() {
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write_slice(&[1, 2]).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    let mut out = [0u8; 3];
+
+    assert_eq!(
+        buffer.read_exact(&mut out).unwrap_err().kind(),
+        io::ErrorKind::UnexpectedEof
+    );
+}
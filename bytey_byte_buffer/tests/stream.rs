@@ -0,0 +1,111 @@
+use bytey_byte_buffer::{
+    byte_buffer::ByteBuffer,
+    error::ByteBufferError,
+    length_prefix::LengthPrefix,
+    stream::{IoSink, IoSource, Sink, Source},
+    varint::VarU64,
+};
+use std::io::{self, BufReader, BufWriter};
+
+fn write_values<S: Sink>(sink: &mut S) -> bytey_byte_buffer::error::Result<()> {
+    sink.write_le(0x0102u16)?;
+    sink.write_be(String::from("bytey"))?;
+    sink.write(vec![Some(1u32), None])?;
+    sink.write(VarU64(300))?;
+
+    Ok(())
+}
+
+#[test]
+fn test_stream_matches_byte_buffer() {
+    let mut buffer = ByteBuffer::new().unwrap();
+    let mut sink = IoSink::new(Vec::new());
+
+    write_values(&mut buffer).unwrap();
+    write_values(&mut sink).unwrap();
+
+    buffer.move_cursor(0).unwrap();
+
+    let bytes = sink.into_inner();
+    assert_eq!(buffer.read_slice(buffer.length()).unwrap(), &bytes[..]);
+}
+
+#[test]
+fn test_stream_write_read() {
+    let mut sink = IoSink::new(BufWriter::new(Vec::new()));
+
+    write_values(&mut sink).unwrap();
+    sink.flush().unwrap();
+
+    let bytes = sink.into_inner().into_inner().unwrap();
+    let mut source = IoSource::new(BufReader::new(&bytes[..]));
+
+    assert_eq!(source.read_le::<u16>().unwrap(), 0x0102);
+    assert_eq!(source.read_be::<String>().unwrap(), "bytey");
+    assert_eq!(source.read::<Vec<Option<u32>>>().unwrap(), [Some(1), None]);
+    assert_eq!(source.read::<VarU64>().unwrap(), VarU64(300));
+    assert!(matches!(
+        source.read::<u8>(),
+        Err(ByteBufferError::ReadOutOfBounds { .. })
+    ));
+}
+
+#[test]
+fn test_stream_length_prefix() {
+    let mut sink = IoSink::new(Vec::new());
+
+    sink.set_length_prefix(LengthPrefix::U8);
+    sink.write("abc").unwrap();
+
+    let bytes = sink.into_inner();
+    assert_eq!(bytes, [3, b'a', b'b', b'c']);
+
+    let mut source = IoSource::new(&bytes[..]);
+
+    source.set_length_prefix(LengthPrefix::U8);
+    assert_eq!(source.read::<String>().unwrap(), "abc");
+}
+
+#[test]
+fn test_stream_truncated_read() {
+    let mut sink = IoSink::new(Vec::new());
+
+    sink.write(u64::MAX).unwrap();
+
+    let bytes = sink.into_inner();
+    let mut source = IoSource::new(&bytes[..5]);
+
+    assert_eq!(
+        source.read::<u64>(),
+        Err(ByteBufferError::ReadOutOfBounds {
+            length: 5,
+            start: 0,
+            end: 8
+        })
+    );
+}
+
+#[test]
+fn test_stream_io_error() {
+    struct Broken;
+
+    impl io::Write for Broken {
+        fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+            Err(io::Error::new(io::ErrorKind::BrokenPipe, "closed"))
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut sink = IoSink::new(Broken);
+
+    assert_eq!(
+        sink.write(1u8),
+        Err(ByteBufferError::Io {
+            kind: io::ErrorKind::BrokenPipe,
+            error: String::from("closed")
+        })
+    );
+}
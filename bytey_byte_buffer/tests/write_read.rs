@@ -471,3 +471,224 @@ fn test_bool_write_read() {
     assert_eq!(read_isfalse_ref, isfalse);
     assert_eq!(read_isfalse, isfalse);
 }
+
+#[test]
+fn test_varu32_write_read() {
+    use bytey_byte_buffer::varint::VarU32;
+
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    for value in [0, 1, 127, 128, 300, 16_383, 16_384, u32::MAX] {
+        let _ = buffer.write(VarU32(value));
+    }
+
+    let _ = buffer.move_cursor(0);
+
+    assert_eq!(buffer.read_slice(4).unwrap(), [0, 1, 127, 0x80]);
+    assert_eq!(
+        buffer.read_slice(6).unwrap(),
+        [0x01, 0xAC, 0x02, 0xFF, 0x7F, 0x80]
+    );
+    assert_eq!(buffer.read_slice(2).unwrap(), [0x80, 0x01]);
+    assert_eq!(buffer.read_slice(5).unwrap(), [0xFF, 0xFF, 0xFF, 0xFF, 0x0F]);
+
+    let _ = buffer.move_cursor(0);
+
+    for value in [0, 1, 127, 128, 300, 16_383, 16_384, u32::MAX] {
+        assert_eq!(buffer.read::<VarU32>().unwrap(), VarU32(value));
+    }
+}
+
+#[test]
+fn test_varu32_invalid_read() {
+    use bytey_byte_buffer::{error::ByteBufferError, varint::VarU32};
+
+    let mut buffer = ByteBuffer::new().unwrap();
+    let _ = buffer.write_slice(&[0x80, 0x00]);
+    let _ = buffer.move_cursor(0);
+
+    assert_eq!(
+        buffer.read::<VarU32>().unwrap_err(),
+        ByteBufferError::VarIntOverlong {
+            type_name: "VarU32"
+        }
+    );
+
+    let mut buffer = ByteBuffer::new().unwrap();
+    let _ = buffer.write_slice(&[0x80, 0x80, 0x80, 0x80, 0x80, 0x01]);
+    let _ = buffer.move_cursor(0);
+
+    assert_eq!(
+        buffer.read::<VarU32>().unwrap_err(),
+        ByteBufferError::VarIntOverflow {
+            type_name: "VarU32"
+        }
+    );
+}
+
+#[test]
+fn test_length_prefix_write_read() {
+    use bytey_byte_buffer::length_prefix::LengthPrefix;
+
+    let value = vec![1u16, 2, 3];
+    let string = String::from("bytey");
+
+    for (prefix, size) in [
+        (LengthPrefix::U8, 1),
+        (LengthPrefix::U16, 2),
+        (LengthPrefix::U32, 4),
+        (LengthPrefix::U64, 8),
+        (LengthPrefix::VarInt, 1),
+    ] {
+        let mut buffer = ByteBuffer::new().unwrap();
+        buffer.set_length_prefix(prefix);
+
+        let _ = buffer.write_be(&value);
+        let _ = buffer.write_be(&string);
+        let _ = buffer.write_be([7u8; 2]);
+
+        assert_eq!(buffer.length(), 3 * size + 6 + 5 + 2);
+
+        let _ = buffer.move_cursor(0);
+
+        assert_eq!(buffer.read_be::<Vec<u16>>().unwrap(), value);
+        assert_eq!(buffer.read_be::<String>().unwrap(), string);
+        assert_eq!(buffer.read_be::<[u8; 2]>().unwrap(), [7u8; 2]);
+    }
+}
+
+#[test]
+fn test_length_prefix_overflow() {
+    use bytey_byte_buffer::{error::ByteBufferError, length_prefix::LengthPrefix};
+
+    let mut buffer = ByteBuffer::new().unwrap();
+    buffer.set_length_prefix(LengthPrefix::U8);
+
+    assert_eq!(
+        buffer.write(vec![0u8; 256]).unwrap_err(),
+        ByteBufferError::LengthOverflow {
+            length: 256,
+            target: "u8"
+        }
+    );
+    assert!(buffer.write(vec![0u8; 255]).is_ok());
+}
+
+#[test]
+fn test_varint_signed_write_read() {
+    use bytey_byte_buffer::varint::{VarI32, VarI64, VarU64};
+
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    let _ = buffer.write(VarI32(0));
+    let _ = buffer.write(VarI32(-1));
+    let _ = buffer.write(VarI32(1));
+    let _ = buffer.write(VarI32(-64));
+    let _ = buffer.write(VarI32(64));
+    let _ = buffer.move_cursor(0);
+
+    assert_eq!(buffer.read_slice(6).unwrap(), [0x00, 0x01, 0x02, 0x7F, 0x80, 0x01]);
+
+    let values = [i64::MIN, -300, -1, 0, 1, 300, i64::MAX];
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    for value in values {
+        let _ = buffer.write_be(VarI64(value));
+        let _ = buffer.write_le(VarU64(value as u64));
+    }
+
+    let _ = buffer.move_cursor(0);
+
+    for value in values {
+        assert_eq!(buffer.read_be::<VarI64>().unwrap(), VarI64(value));
+        assert_eq!(buffer.read_le::<VarU64>().unwrap(), VarU64(value as u64));
+    }
+
+    assert_eq!(buffer.cursor(), buffer.length());
+
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    let _ = buffer.write(VarI32(i32::MIN));
+    let _ = buffer.write(VarI32(i32::MAX));
+    let _ = buffer.move_cursor(0);
+
+    assert_eq!(buffer.read::<VarI32>().unwrap(), VarI32(i32::MIN));
+    assert_eq!(buffer.read::<VarI32>().unwrap(), VarI32(i32::MAX));
+}
+
+#[test]
+fn test_varint_64_invalid_read() {
+    use bytey_byte_buffer::{
+        error::ByteBufferError,
+        varint::{VarI32, VarU64},
+    };
+
+    let mut buffer = ByteBuffer::new().unwrap();
+    let _ = buffer.write_slice(&[0xFF; 9]);
+    let _ = buffer.write_slice(&[0x02]);
+    let _ = buffer.move_cursor(0);
+
+    assert_eq!(
+        buffer.read::<VarU64>().unwrap_err(),
+        ByteBufferError::VarIntOverflow {
+            type_name: "VarU64"
+        }
+    );
+
+    let mut buffer = ByteBuffer::new().unwrap();
+    let _ = buffer.write_slice(&[0xFF, 0xFF, 0xFF, 0xFF, 0x1F]);
+    let _ = buffer.move_cursor(0);
+
+    assert_eq!(
+        buffer.read::<VarI32>().unwrap_err(),
+        ByteBufferError::VarIntOverflow {
+            type_name: "VarI32"
+        }
+    );
+
+    let mut buffer = ByteBuffer::new().unwrap();
+    let _ = buffer.write_slice(&[0x81, 0x80, 0x00]);
+    let _ = buffer.move_cursor(0);
+
+    assert_eq!(
+        buffer.read::<VarU64>().unwrap_err(),
+        ByteBufferError::VarIntOverlong {
+            type_name: "VarU64"
+        }
+    );
+}
+
+#[test]
+fn test_smart_pointers_write_read() {
+    use std::{rc::Rc, sync::Arc};
+
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write(Arc::new(7u32)).unwrap();
+    buffer.write_le(Rc::new(String::from("rc"))).unwrap();
+    buffer.write_be(Arc::<str>::from("interned")).unwrap();
+    buffer.write(Rc::<[u16]>::from([1, 2])).unwrap();
+    buffer.write(Box::<str>::from("boxed")).unwrap();
+    buffer.write_be(Box::<[u16]>::from([3, 4])).unwrap();
+
+    let start = buffer.length();
+
+    // The same encoding as the inner types.
+    buffer.write(7u32).unwrap();
+    buffer.write_le("rc").unwrap();
+    buffer.write_be("interned").unwrap();
+    buffer.write(vec![1u16, 2]).unwrap();
+    buffer.write("boxed").unwrap();
+    buffer.write_be(vec![3u16, 4]).unwrap();
+
+    assert_eq!(buffer.as_bytes()[..start], buffer.as_bytes()[start..]);
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(*buffer.read::<Arc<u32>>().unwrap(), 7);
+    assert_eq!(*buffer.read_le::<Rc<String>>().unwrap(), "rc");
+    assert_eq!(&*buffer.read_be::<Arc<str>>().unwrap(), "interned");
+    assert_eq!(*buffer.read::<Rc<[u16]>>().unwrap(), [1, 2]);
+    assert_eq!(&*buffer.read::<Box<str>>().unwrap(), "boxed");
+    assert_eq!(*buffer.read_be::<Box<[u16]>>().unwrap(), [3, 4]);
+}
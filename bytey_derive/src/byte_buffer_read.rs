@@ -1,286 +1,308 @@
 use crate::{
-    field_wrapper::is_skipped,
-    source::{EnumSource, StructSource},
+    endian::Endian,
+    field_wrapper::FieldAttributes,
+    source::{EnumSource, StructSource, UnionSource},
 };
-use quote::quote;
+use quote::{format_ident, quote};
+
+/// The trait a derived read impl is generated for.
+#[derive(Copy, Clone)]
+pub enum ReadTarget {
+    /// `ByteBufferRead`, reading from any `Source` like a `ByteBuffer`.
+    Owned,
+    /// `ByteBufferReadRef`, reading from a `ByteBufferRef` so fields can borrow from it.
+    Borrowed,
+}
 
-pub fn expand_derive_byte_buffer_read(input: &syn::DeriveInput) -> proc_macro2::TokenStream {
+pub fn expand_derive_byte_buffer_read(
+    input: &syn::DeriveInput,
+    target: ReadTarget,
+) -> syn::Result<proc_macro2::TokenStream> {
     match &input.data {
         syn::Data::Struct(_) => {
-            let source = StructSource::from_input(input);
+            let source = StructSource::from_input(input)?;
 
-            handle_struct(source)
+            handle_struct(source, target)
         }
         syn::Data::Enum(_) => {
-            let source = EnumSource::from_input(input);
+            let source = EnumSource::from_input(input)?;
 
-            handle_enum(source)
+            handle_enum(source, target)
         }
         syn::Data::Union(_) => {
-            input
-                .ident
-                .span()
-                .unwrap()
-                .error("Unions are currently not supported")
-                .emit();
-
-            proc_macro2::TokenStream::new()
+            let source = UnionSource::from_input(input)?;
+
+            Ok(handle_union(source, target))
         }
     }
 }
 
-fn handle_struct(input: StructSource) -> proc_macro2::TokenStream {
-    let struct_ident = input.ident;
-    let init_struct_native: proc_macro2::TokenStream;
-    let init_struct_le: proc_macro2::TokenStream;
-    let init_struct_be: proc_macro2::TokenStream;
-
-    match input.fields {
-        syn::Fields::Named(syn::FieldsNamed { named, .. }) => {
-            let mut field_reads_native: Vec<proc_macro2::TokenStream> = Vec::new();
-            let mut field_reads_le: Vec<proc_macro2::TokenStream> = Vec::new();
-            let mut field_reads_be: Vec<proc_macro2::TokenStream> = Vec::new();
-
-            for field in named {
-                let field_ident = field.ident.as_ref().unwrap();
-                let field_ty = &field.ty;
-
-                field_reads_native.push(if is_skipped(field) {
-                    quote! {#field_ident: Default::default()}
-                } else {
-                    quote! {#field_ident: buffer.read::<#field_ty>()?}
-                });
-
-                field_reads_le.push(if is_skipped(field) {
-                    quote! {#field_ident: Default::default()}
-                } else {
-                    quote! {#field_ident: buffer.read_le::<#field_ty>()?}
-                });
-
-                field_reads_be.push(if is_skipped(field) {
-                    quote! {#field_ident: Default::default()}
-                } else {
-                    quote! {#field_ident: buffer.read_be::<#field_ty>()?}
-                });
-            }
-
-            init_struct_native = quote! {
-                #struct_ident {
-                    #(#field_reads_native),*
-                }
-            };
+/// Wraps the bodies of the three read methods in an impl of the target trait.
+fn impl_read(
+    target: ReadTarget,
+    ident: &syn::Ident,
+    generics: &syn::Generics,
+    native: proc_macro2::TokenStream,
+    le: proc_macro2::TokenStream,
+    be: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let (_, ty_generics, where_clause) = generics.split_for_impl();
+
+    match target {
+        ReadTarget::Owned => {
+            let (impl_generics, _, _) = generics.split_for_impl();
+
+            quote! {
+                impl #impl_generics ::bytey::ByteBufferRead for #ident #ty_generics #where_clause {
+                    #[inline]
+                    fn read_from_bytey_buffer<__ByteySource: ::bytey::Source>(buffer: &mut __ByteySource) -> ::bytey::Result<#ident #ty_generics> {
+                        #native
+                    }
 
-            init_struct_le = quote! {
-                #struct_ident {
-                    #(#field_reads_le),*
-                }
-            };
+                    #[inline]
+                    fn read_from_bytey_buffer_le<__ByteySource: ::bytey::Source>(buffer: &mut __ByteySource) -> ::bytey::Result<#ident #ty_generics> {
+                        #le
+                    }
 
-            init_struct_be = quote! {
-                #struct_ident {
-                    #(#field_reads_be),*
+                    #[inline]
+                    fn read_from_bytey_buffer_be<__ByteySource: ::bytey::Source>(buffer: &mut __ByteySource) -> ::bytey::Result<#ident #ty_generics> {
+                        #be
+                    }
                 }
-            };
+            }
         }
-        syn::Fields::Unnamed(syn::FieldsUnnamed { unnamed, .. }) => {
-            let mut field_reads_native: Vec<proc_macro2::TokenStream> = Vec::new();
-            let mut field_reads_le: Vec<proc_macro2::TokenStream> = Vec::new();
-            let mut field_reads_be: Vec<proc_macro2::TokenStream> = Vec::new();
-
-            for field in unnamed {
-                let field_ty = &field.ty;
-
-                field_reads_native.push(if is_skipped(field) {
-                    quote! { Default::default() }
+        ReadTarget::Borrowed => {
+            // The lifetime of the buffer has to outlive every lifetime the type borrows for.
+            let buffer_lifetime = syn::Lifetime::new("'bytey", proc_macro2::Span::call_site());
+            let mut ref_generics = generics.clone();
+            let bounds: Vec<&syn::Lifetime> = generics.lifetimes().map(|l| &l.lifetime).collect();
+
+            ref_generics.params.insert(
+                0,
+                if bounds.is_empty() {
+                    syn::parse_quote! { #buffer_lifetime }
                 } else {
-                    quote! { buffer.read::<#field_ty>()?}
-                });
+                    syn::parse_quote! { #buffer_lifetime: #(#bounds)+* }
+                },
+            );
 
-                field_reads_le.push(if is_skipped(field) {
-                    quote! { Default::default() }
-                } else {
-                    quote! { buffer.read_le::<#field_ty>()?}
-                });
+            let (impl_generics, _, _) = ref_generics.split_for_impl();
 
-                field_reads_be.push(if is_skipped(field) {
-                    quote! { Default::default() }
-                } else {
-                    quote! { buffer.read_be::<#field_ty>()?}
-                });
-            }
+            // The `Source` methods of the `ByteBufferRef`, like `read_nested`, are used by the bodies.
+            let [native, le, be] = [native, le, be].map(|body| {
+                quote! {
+                    use ::bytey::Source as _;
 
-            init_struct_native = quote! {
-                #struct_ident (
-                    #(#field_reads_native),*
-                )
-            };
-
-            init_struct_le = quote! {
-                #struct_ident (
-                    #(#field_reads_le),*
-                )
-            };
-
-            init_struct_be = quote! {
-                #struct_ident (
-                    #(#field_reads_be),*
-                )
-            };
-        }
-        syn::Fields::Unit => {
-            input
-                .ident
-                .span()
-                .unwrap()
-                .error("Unit structs are currently not supported")
-                .emit();
-
-            return proc_macro2::TokenStream::new();
-        }
-    }
-
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+                    #body
+                }
+            });
 
-    quote! {
-        impl #impl_generics ::bytey::ByteBufferRead for #struct_ident #ty_generics #where_clause {
-            #[inline]
-            fn read_from_bytey_buffer(buffer: &mut ::bytey::ByteBuffer) -> ::bytey::Result<#struct_ident #ty_generics> {
-                Ok(#init_struct_native)
-            }
+            quote! {
+                impl #impl_generics ::bytey::ByteBufferReadRef<#buffer_lifetime> for #ident #ty_generics #where_clause {
+                    #[inline]
+                    fn read_ref_from_bytey_buffer(buffer: &mut ::bytey::ByteBufferRef<#buffer_lifetime>) -> ::bytey::Result<#ident #ty_generics> {
+                        #native
+                    }
 
-            #[inline]
-            fn read_from_bytey_buffer_le(buffer: &mut ::bytey::ByteBuffer) -> ::bytey::Result<#struct_ident #ty_generics> {
-                Ok(#init_struct_le)
-            }
+                    #[inline]
+                    fn read_ref_from_bytey_buffer_le(buffer: &mut ::bytey::ByteBufferRef<#buffer_lifetime>) -> ::bytey::Result<#ident #ty_generics> {
+                        #le
+                    }
 
-            #[inline]
-            fn read_from_bytey_buffer_be(buffer: &mut ::bytey::ByteBuffer) -> ::bytey::Result<#struct_ident #ty_generics> {
-                Ok(#init_struct_be)
+                    #[inline]
+                    fn read_ref_from_bytey_buffer_be(buffer: &mut ::bytey::ByteBufferRef<#buffer_lifetime>) -> ::bytey::Result<#ident #ty_generics> {
+                        #be
+                    }
+                }
             }
         }
     }
 }
 
-fn handle_enum(input: EnumSource) -> proc_macro2::TokenStream {
+fn handle_struct(input: StructSource, target: ReadTarget) -> syn::Result<proc_macro2::TokenStream> {
+    let struct_ident = input.ident;
+    let path = quote! { #struct_ident };
+    let name = struct_ident.to_string();
+    let init_struct_native = init_fields(&path, &name, input.fields, input.endian, target, "read")?;
+    let init_struct_le = init_fields(&path, &name, input.fields, input.endian, target, "read_le")?;
+    let init_struct_be = init_fields(&path, &name, input.fields, input.endian, target, "read_be")?;
+
+    Ok(impl_read(
+        target,
+        struct_ident,
+        input.generics,
+        read_nested(quote! { Ok(#init_struct_native) }),
+        read_nested(quote! { Ok(#init_struct_le) }),
+        read_nested(quote! { Ok(#init_struct_be) }),
+    ))
+}
+
+fn handle_enum(input: EnumSource, target: ReadTarget) -> syn::Result<proc_macro2::TokenStream> {
     let enum_ident = input.ident;
     let mut match_arms_native: Vec<proc_macro2::TokenStream> = Vec::new();
     let mut match_arms_le: Vec<proc_macro2::TokenStream> = Vec::new();
     let mut match_arms_be: Vec<proc_macro2::TokenStream> = Vec::new();
-    let mut id: u16 = 1;
 
     for variant in &input.variants {
         let variant_ident = variant.ident;
+        let id = input.tag.literal(variant.id);
+        let path = quote! { #enum_ident::#variant_ident };
+        let name = format!("{enum_ident}::{variant_ident}");
+
+        let init_native = init_fields(&path, &name, variant.fields, input.endian, target, "read")?;
+        let init_le = init_fields(
+            &path,
+            &name,
+            variant.fields,
+            input.endian,
+            target,
+            "read_le",
+        )?;
+        let init_be = init_fields(
+            &path,
+            &name,
+            variant.fields,
+            input.endian,
+            target,
+            "read_be",
+        )?;
+
+        match_arms_native.push(quote! {
+            #id => Ok(#init_native)
+        });
+
+        match_arms_le.push(quote! {
+            #id => Ok(#init_le)
+        });
+
+        match_arms_be.push(quote! {
+            #id => Ok(#init_be)
+        });
+    }
 
-        match variant.fields {
-            syn::Fields::Named(syn::FieldsNamed { named, .. }) => {
-                let mut field_idents: Vec<&syn::Ident> = Vec::new();
-                let mut field_tys: Vec<&syn::Type> = Vec::new();
+    let enum_name = enum_ident.to_string();
+    let read_id_native = input.tag.read(read_method(input.endian, "read"));
+    let read_id_le = input.tag.read(read_method(input.endian, "read_le"));
+    let read_id_be = input.tag.read(read_method(input.endian, "read_be"));
+
+    Ok(impl_read(
+        target,
+        enum_ident,
+        input.generics,
+        read_nested(quote! {
+            match #read_id_native {
+                #(#match_arms_native,)*
+                tag => Err(::bytey::ByteBufferError::InvalidTag { type_name: #enum_name, tag: tag.into() })
+            }
+        }),
+        read_nested(quote! {
+            match #read_id_le {
+                #(#match_arms_le,)*
+                tag => Err(::bytey::ByteBufferError::InvalidTag { type_name: #enum_name, tag: tag.into() })
+            }
+        }),
+        read_nested(quote! {
+            match #read_id_be {
+                #(#match_arms_be,)*
+                tag => Err(::bytey::ByteBufferError::InvalidTag { type_name: #enum_name, tag: tag.into() })
+            }
+        }),
+    ))
+}
 
-                for field in named {
-                    field_idents.push(field.ident.as_ref().unwrap());
-                    field_tys.push(&field.ty);
-                }
+/// Reads the fields one level deeper, so the max depth of the `DecodeLimits` bounds recursive types.
+fn read_nested(body: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    quote! {
+        buffer.read_nested(|buffer| { #body })
+    }
+}
 
-                match_arms_native.push(quote! {
-                    #id => {
-                        Ok(#enum_ident::#variant_ident {
-                            #( #field_idents: buffer.read::<#field_tys>()? ),*
-                        })
-                    }
-                });
+/// The read method for the fixed endian of the container, or the given method if it has none.
+fn read_method(endian: Option<Endian>, method: &str) -> syn::Ident {
+    format_ident!("{}", endian.map_or(method, |e| e.read_method()))
+}
 
-                match_arms_le.push(quote! {
-                    #id => {
-                        Ok(#enum_ident::#variant_ident {
-                            #( #field_idents: buffer.read_le::<#field_tys>()? ),*
-                        })
-                    }
-                });
+/// Builds the struct or variant at `path`, reading every field with the given `ByteBuffer` read method.
+///
+/// `name` is the name of the struct or variant used in the path of read errors.
+fn init_fields(
+    path: &proc_macro2::TokenStream,
+    name: &str,
+    fields: &syn::Fields,
+    endian: Option<Endian>,
+    target: ReadTarget,
+    method: &str,
+) -> syn::Result<proc_macro2::TokenStream> {
+    match fields {
+        syn::Fields::Named(syn::FieldsNamed { named, .. }) => {
+            let mut field_reads: Vec<proc_macro2::TokenStream> = Vec::new();
 
-                match_arms_be.push(quote! {
-                    #id => {
-                        Ok(#enum_ident::#variant_ident {
-                            #( #field_idents: buffer.read_be::<#field_tys>()? ),*
-                        })
-                    }
-                });
+            for field in named {
+                let field_ident = field.ident.as_ref().unwrap();
+                let field_name = field_ident.to_string();
+                let read = FieldAttributes::from_field(field, endian)?.read(
+                    &field.ty,
+                    target,
+                    method,
+                    name,
+                    field_name.trim_start_matches("r#"),
+                );
+
+                field_reads.push(quote! { #field_ident: #read });
             }
-            syn::Fields::Unnamed(syn::FieldsUnnamed { unnamed, .. }) => {
-                let mut field_tys: Vec<&syn::Type> = Vec::new();
 
-                for field in unnamed {
-                    field_tys.push(&field.ty);
+            Ok(quote! {
+                #path {
+                    #(#field_reads),*
                 }
-
-                match_arms_native.push(quote! {
-                    #id => {
-                        Ok(#enum_ident::#variant_ident (
-                            #( buffer.read::<#field_tys>()? ),*
-                        ))
-                    }
-                });
-
-                match_arms_le.push(quote! {
-                    #id => {
-                        Ok(#enum_ident::#variant_ident (
-                            #( buffer.read_le::<#field_tys>()? ),*
-                        ))
-                    }
-                });
-
-                match_arms_be.push(quote! {
-                    #id => {
-                        Ok(#enum_ident::#variant_ident (
-                            #( buffer.read_be::<#field_tys>()? ),*
-                        ))
-                    }
-                });
-            }
-            syn::Fields::Unit => {
-                match_arms_native.push(quote! {
-                    #id => Ok(#enum_ident::#variant_ident)
-                });
-
-                match_arms_le.push(quote! {
-                    #id => Ok(#enum_ident::#variant_ident)
-                });
-
-                match_arms_be.push(quote! {
-                    #id => Ok(#enum_ident::#variant_ident)
-                });
-            }
+            })
         }
+        syn::Fields::Unnamed(syn::FieldsUnnamed { unnamed, .. }) => {
+            let mut field_reads: Vec<proc_macro2::TokenStream> = Vec::new();
+
+            for (index, field) in unnamed.iter().enumerate() {
+                field_reads.push(FieldAttributes::from_field(field, endian)?.read(
+                    &field.ty,
+                    target,
+                    method,
+                    name,
+                    &index.to_string(),
+                ));
+            }
 
-        id += 1;
+            Ok(quote! {
+                #path (
+                    #(#field_reads),*
+                )
+            })
+        }
+        syn::Fields::Unit => Ok(quote! { #path }),
     }
+}
 
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+fn handle_union(input: UnionSource, target: ReadTarget) -> proc_macro2::TokenStream {
+    let assertions = input.assertions();
+    let read_bytes = quote! {
+        #assertions
 
-    quote! {
-        impl #impl_generics ::bytey::ByteBufferRead for #enum_ident #ty_generics #where_clause {
-            #[inline]
-            fn read_from_bytey_buffer(buffer: &mut ::bytey::ByteBuffer) -> ::bytey::Result<#enum_ident #ty_generics> {
-                match buffer.read::<u16>()? {
-                    #(#match_arms_native,)*
-                    id => Err(::bytey::ByteBufferError::OtherError { error: ::std::format!("Invalid id: {}", id) })
-                }
-            }
+        let bytes = buffer.read_slice(::core::mem::size_of::<Self>())?;
+        let mut value = ::core::mem::MaybeUninit::<Self>::uninit();
 
-            #[inline]
-            fn read_from_bytey_buffer_le(buffer: &mut ::bytey::ByteBuffer) -> ::bytey::Result<#enum_ident #ty_generics> {
-                match buffer.read::<u16>()? {
-                    #(#match_arms_le,)*
-                    id => Err(::bytey::ByteBufferError::OtherError { error: ::std::format!("Invalid id: {}", id) })
-                }
-            }
+        // SAFETY: the union only has `NoUninit` fields that each span the whole union, so the bytes fully
+        // initialize it and are a valid value of every field.
+        unsafe {
+            ::core::ptr::copy_nonoverlapping(bytes.as_ptr(), value.as_mut_ptr() as *mut u8, bytes.len());
 
-            #[inline]
-            fn read_from_bytey_buffer_be(buffer: &mut ::bytey::ByteBuffer) -> ::bytey::Result<#enum_ident #ty_generics> {
-                match buffer.read::<u16>()? {
-                    #(#match_arms_be,)*
-                    id => Err(::bytey::ByteBufferError::OtherError { error: ::std::format!("Invalid id: {}", id) })
-                }
-            }
+            Ok(value.assume_init())
         }
-    }
+    };
+
+    impl_read(
+        target,
+        input.ident,
+        input.generics,
+        read_bytes.clone(),
+        read_bytes.clone(),
+        read_bytes,
+    )
 }
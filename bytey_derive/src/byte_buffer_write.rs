@@ -1,95 +1,81 @@
-use crate::field_wrapper::{is_skipped, FieldWrapper};
-use crate::source::{EnumSource, StructSource};
-use quote::quote;
+use crate::endian::Endian;
+use crate::field_wrapper::{FieldAttributes, FieldWrapper};
+use crate::source::{EnumSource, StructSource, UnionSource};
+use quote::{format_ident, quote};
 use syn::spanned::Spanned;
 
-pub fn expand_derive_byte_buffer_write(input: &syn::DeriveInput) -> proc_macro2::TokenStream {
+pub fn expand_derive_byte_buffer_write(
+    input: &syn::DeriveInput,
+) -> syn::Result<proc_macro2::TokenStream> {
     match &input.data {
         syn::Data::Struct(_) => {
-            let source = StructSource::from_input(input);
+            let source = StructSource::from_input(input)?;
 
             handle_struct(source)
         }
         syn::Data::Enum(_) => {
-            let source = EnumSource::from_input(input);
+            let source = EnumSource::from_input(input)?;
 
             handle_enum(source)
         }
         syn::Data::Union(_) => {
-            input
-                .ident
-                .span()
-                .unwrap()
-                .error("Unions are currently not supported")
-                .emit();
-
-            proc_macro2::TokenStream::new()
+            let source = UnionSource::from_input(input)?;
+
+            Ok(handle_union(source))
         }
     }
 }
 
-fn handle_struct(input: StructSource) -> proc_macro2::TokenStream {
-    let mut fields: Vec<FieldWrapper> = Vec::new();
+fn handle_struct(input: StructSource) -> syn::Result<proc_macro2::TokenStream> {
+    let mut field_writes_native: Vec<proc_macro2::TokenStream> = Vec::new();
+    let mut field_writes_le: Vec<proc_macro2::TokenStream> = Vec::new();
+    let mut field_writes_be: Vec<proc_macro2::TokenStream> = Vec::new();
 
-    match input.fields {
-        syn::Fields::Named(syn::FieldsNamed { named, .. }) => {
-            for field in named {
-                if is_skipped(field) {
-                    continue;
-                }
+    for (count, field) in input.fields.iter().enumerate() {
+        let attrs = FieldAttributes::from_field(field, input.endian)?;
 
-                fields.push(FieldWrapper {
-                    field: Some(field.ident.as_ref().unwrap()),
-                    index: None,
-                });
-            }
+        if attrs.skip {
+            continue;
         }
-        syn::Fields::Unnamed(syn::FieldsUnnamed { unnamed, .. }) => {
-            for (count, field) in unnamed.into_iter().enumerate() {
-                if is_skipped(field) {
-                    continue;
-                }
 
-                fields.push(FieldWrapper {
-                    field: None,
-                    index: Some(syn::Index::from(count)),
-                });
-            }
-        }
-        syn::Fields::Unit => {
-            input
-                .ident
-                .span()
-                .unwrap()
-                .error("Unit structs are currently not supported")
-                .emit();
-
-            return proc_macro2::TokenStream::new();
-        }
+        let member = match &field.ident {
+            Some(ident) => FieldWrapper {
+                field: Some(ident),
+                index: None,
+            },
+            None => FieldWrapper {
+                field: None,
+                index: Some(syn::Index::from(count)),
+            },
+        };
+
+        field_writes_native.push(attrs.write(quote! { &self.#member }, "write_to_bytey_buffer"));
+        field_writes_le.push(attrs.write(quote! { &self.#member }, "write_to_bytey_buffer_le"));
+        field_writes_be.push(attrs.write(quote! { &self.#member }, "write_to_bytey_buffer_be"));
     }
 
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
     let ident = input.ident;
 
-    quote! {
+    Ok(quote! {
         impl #impl_generics ::bytey::ByteBufferWrite for #ident #ty_generics #where_clause {
             #[inline]
-            fn write_to_bytey_buffer(&self, buffer: &mut ::bytey::ByteBuffer) -> ::bytey::Result<()> {
-                #(self.#fields.write_to_bytey_buffer(buffer)?;)*
+            fn write_to_bytey_buffer<__ByteySink: ::bytey::Sink>(&self, buffer: &mut __ByteySink) -> ::bytey::Result<()> {
+                #(#field_writes_native)*
 
                 Ok(())
             }
 
             #[inline]
-            fn write_to_bytey_buffer_le(&self, buffer: &mut ::bytey::ByteBuffer) -> ::bytey::Result<()> {
-                #(self.#fields.write_to_bytey_buffer_le(buffer)?;)*
+            fn write_to_bytey_buffer_le<__ByteySink: ::bytey::Sink>(&self, buffer: &mut __ByteySink) -> ::bytey::Result<()> {
+                #(#field_writes_le)*
 
                 Ok(())
             }
 
             #[inline]
-            fn write_to_bytey_buffer_be(&self, buffer: &mut ::bytey::ByteBuffer) -> ::bytey::Result<()> {
-                #(self.#fields.write_to_bytey_buffer_be(buffer)?;)*
+            fn write_to_bytey_buffer_be<__ByteySink: ::bytey::Sink>(&self, buffer: &mut __ByteySink) -> ::bytey::Result<()> {
+                #(#field_writes_be)*
 
                 Ok(())
             }
@@ -97,91 +83,106 @@ fn handle_struct(input: StructSource) -> proc_macro2::TokenStream {
 
         impl #impl_generics ::bytey::ByteBufferWrite for &#ident #ty_generics #where_clause {
             #[inline]
-            fn write_to_bytey_buffer(&self, buffer: &mut ::bytey::ByteBuffer) -> ::bytey::Result<()> {
-                #(self.#fields.write_to_bytey_buffer(buffer)?;)*
+            fn write_to_bytey_buffer<__ByteySink: ::bytey::Sink>(&self, buffer: &mut __ByteySink) -> ::bytey::Result<()> {
+                #(#field_writes_native)*
 
                 Ok(())
             }
 
             #[inline]
-            fn write_to_bytey_buffer_le(&self, buffer: &mut ::bytey::ByteBuffer) -> ::bytey::Result<()> {
-                #(self.#fields.write_to_bytey_buffer_le(buffer)?;)*
+            fn write_to_bytey_buffer_le<__ByteySink: ::bytey::Sink>(&self, buffer: &mut __ByteySink) -> ::bytey::Result<()> {
+                #(#field_writes_le)*
 
                 Ok(())
             }
 
             #[inline]
-            fn write_to_bytey_buffer_be(&self, buffer: &mut ::bytey::ByteBuffer) -> ::bytey::Result<()> {
-                #(self.#fields.write_to_bytey_buffer_be(buffer)?;)*
+            fn write_to_bytey_buffer_be<__ByteySink: ::bytey::Sink>(&self, buffer: &mut __ByteySink) -> ::bytey::Result<()> {
+                #(#field_writes_be)*
 
                 Ok(())
             }
         }
-    }
+    })
 }
 
-fn handle_enum(input: EnumSource) -> proc_macro2::TokenStream {
+fn handle_enum(input: EnumSource) -> syn::Result<proc_macro2::TokenStream> {
     let enum_ident = input.ident;
     let mut variants_native: Vec<proc_macro2::TokenStream> = Vec::new();
     let mut variants_le: Vec<proc_macro2::TokenStream> = Vec::new();
     let mut variants_be: Vec<proc_macro2::TokenStream> = Vec::new();
-    let mut id: u16 = 1;
+
+    let write_id_native = write_method(input.endian, "write_to_bytey_buffer");
+    let write_id_le = write_method(input.endian, "write_to_bytey_buffer_le");
+    let write_id_be = write_method(input.endian, "write_to_bytey_buffer_be");
 
     for variant in &input.variants {
-        let mut field_idents: Vec<syn::Ident> = Vec::new();
+        let mut field_patterns: Vec<proc_macro2::TokenStream> = Vec::new();
+        let mut field_writes_native: Vec<proc_macro2::TokenStream> = Vec::new();
+        let mut field_writes_le: Vec<proc_macro2::TokenStream> = Vec::new();
+        let mut field_writes_be: Vec<proc_macro2::TokenStream> = Vec::new();
         let variant_ident = variant.ident;
+        let id = input.tag.value(variant.id);
+
+        for (count, field) in variant.fields.iter().enumerate() {
+            let field_ident = match &field.ident {
+                Some(ident) => ident.clone(),
+                None => syn::Ident::new(format!("val{count}").as_str(), field.span()),
+            };
+            let attrs = FieldAttributes::from_field(field, input.endian)?;
+
+            if attrs.skip {
+                field_patterns.push(match &field.ident {
+                    Some(_) => quote! { #field_ident: _ },
+                    None => quote! { _ },
+                });
+                continue;
+            }
 
-        let variant_match_case = match variant.fields {
-            syn::Fields::Named(syn::FieldsNamed { named, .. }) => {
-                for field in named {
-                    field_idents.push(field.ident.as_ref().unwrap().clone());
-                }
+            field_writes_native.push(attrs.write(quote! { #field_ident }, "write_to_bytey_buffer"));
+            field_writes_le.push(attrs.write(quote! { #field_ident }, "write_to_bytey_buffer_le"));
+            field_writes_be.push(attrs.write(quote! { #field_ident }, "write_to_bytey_buffer_be"));
+            field_patterns.push(quote! { #field_ident });
+        }
 
-                quote! { #enum_ident::#variant_ident { #(#field_idents),* } }
+        let variant_match_case = match variant.fields {
+            syn::Fields::Named(_) => {
+                quote! { #enum_ident::#variant_ident { #(#field_patterns),* } }
             }
-            syn::Fields::Unnamed(syn::FieldsUnnamed { unnamed, .. }) => {
-                for (count, field) in unnamed.into_iter().enumerate() {
-                    field_idents.push(syn::Ident::new(
-                        format!("val{count}").as_str(),
-                        field.span(),
-                    ));
-                }
-
-                quote! { #enum_ident::#variant_ident ( #(#field_idents),* ) }
+            syn::Fields::Unnamed(_) => {
+                quote! { #enum_ident::#variant_ident ( #(#field_patterns),* ) }
             }
             syn::Fields::Unit => quote! {#enum_ident::#variant_ident},
         };
 
         variants_native.push(quote! {
             #variant_match_case => {
-                #id.write_to_bytey_buffer(buffer)?;
-                #(#field_idents.write_to_bytey_buffer(buffer)?;)*
+                #id.#write_id_native(buffer)?;
+                #(#field_writes_native)*
             }
         });
 
         variants_le.push(quote! {
             #variant_match_case => {
-                #id.write_to_bytey_buffer_le(buffer)?;
-                #(#field_idents.write_to_bytey_buffer_le(buffer)?;)*
+                #id.#write_id_le(buffer)?;
+                #(#field_writes_le)*
             }
         });
 
         variants_be.push(quote! {
             #variant_match_case => {
-                #id.write_to_bytey_buffer_be(buffer)?;
-                #(#field_idents.write_to_bytey_buffer_be(buffer)?;)*
+                #id.#write_id_be(buffer)?;
+                #(#field_writes_be)*
             }
         });
-
-        id += 1;
     }
 
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
-    quote! {
+    Ok(quote! {
         impl #impl_generics ::bytey::ByteBufferWrite for #enum_ident #ty_generics #where_clause {
             #[inline]
-            fn write_to_bytey_buffer(&self, buffer: &mut ::bytey::ByteBuffer) -> ::bytey::Result<()> {
+            fn write_to_bytey_buffer<__ByteySink: ::bytey::Sink>(&self, buffer: &mut __ByteySink) -> ::bytey::Result<()> {
                 match self {
                     #(#variants_native),*
                 }
@@ -190,7 +191,7 @@ fn handle_enum(input: EnumSource) -> proc_macro2::TokenStream {
             }
 
             #[inline]
-            fn write_to_bytey_buffer_le(&self, buffer: &mut ::bytey::ByteBuffer) -> ::bytey::Result<()> {
+            fn write_to_bytey_buffer_le<__ByteySink: ::bytey::Sink>(&self, buffer: &mut __ByteySink) -> ::bytey::Result<()> {
                 match self {
                     #(#variants_le),*
                 }
@@ -199,7 +200,7 @@ fn handle_enum(input: EnumSource) -> proc_macro2::TokenStream {
             }
 
             #[inline]
-            fn write_to_bytey_buffer_be(&self, buffer: &mut ::bytey::ByteBuffer) -> ::bytey::Result<()> {
+            fn write_to_bytey_buffer_be<__ByteySink: ::bytey::Sink>(&self, buffer: &mut __ByteySink) -> ::bytey::Result<()> {
                 match self {
                     #(#variants_be),*
                 }
@@ -210,7 +211,7 @@ fn handle_enum(input: EnumSource) -> proc_macro2::TokenStream {
 
         impl #impl_generics ::bytey::ByteBufferWrite for &#enum_ident #ty_generics #where_clause {
             #[inline]
-            fn write_to_bytey_buffer(&self, buffer: &mut ::bytey::ByteBuffer) -> ::bytey::Result<()> {
+            fn write_to_bytey_buffer<__ByteySink: ::bytey::Sink>(&self, buffer: &mut __ByteySink) -> ::bytey::Result<()> {
                 match self {
                     #(#variants_native),*
                 }
@@ -219,7 +220,7 @@ fn handle_enum(input: EnumSource) -> proc_macro2::TokenStream {
             }
 
             #[inline]
-            fn write_to_bytey_buffer_le(&self, buffer: &mut ::bytey::ByteBuffer) -> ::bytey::Result<()> {
+            fn write_to_bytey_buffer_le<__ByteySink: ::bytey::Sink>(&self, buffer: &mut __ByteySink) -> ::bytey::Result<()> {
                 match self {
                     #(#variants_le),*
                 }
@@ -228,7 +229,7 @@ fn handle_enum(input: EnumSource) -> proc_macro2::TokenStream {
             }
 
             #[inline]
-            fn write_to_bytey_buffer_be(&self, buffer: &mut ::bytey::ByteBuffer) -> ::bytey::Result<()> {
+            fn write_to_bytey_buffer_be<__ByteySink: ::bytey::Sink>(&self, buffer: &mut __ByteySink) -> ::bytey::Result<()> {
                 match self {
                     #(#variants_be),*
                 }
@@ -236,5 +237,68 @@ fn handle_enum(input: EnumSource) -> proc_macro2::TokenStream {
                 Ok(())
             }
         }
+    })
+}
+
+/// The write method for the fixed endian of the container, or the given method if it has none.
+fn write_method(endian: Option<Endian>, method: &str) -> syn::Ident {
+    format_ident!("{}", endian.map_or(method, |e| e.write_method()))
+}
+
+fn handle_union(input: UnionSource) -> proc_macro2::TokenStream {
+    let union_ident = input.ident;
+    let assertions = input.assertions();
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let write_bytes = quote! {
+        #assertions
+
+        // SAFETY: the union only has `NoUninit` fields that each span the whole union, so all of its bytes are initialized.
+        let bytes = unsafe {
+            ::core::slice::from_raw_parts(
+                self as *const #union_ident #ty_generics as *const u8,
+                ::core::mem::size_of::<#union_ident #ty_generics>(),
+            )
+        };
+
+        buffer.write_slice(bytes)?;
+
+        Ok(())
+    };
+
+    quote! {
+        impl #impl_generics ::bytey::ByteBufferWrite for #union_ident #ty_generics #where_clause {
+            #[inline]
+            fn write_to_bytey_buffer<__ByteySink: ::bytey::Sink>(&self, buffer: &mut __ByteySink) -> ::bytey::Result<()> {
+                #write_bytes
+            }
+
+            #[inline]
+            fn write_to_bytey_buffer_le<__ByteySink: ::bytey::Sink>(&self, buffer: &mut __ByteySink) -> ::bytey::Result<()> {
+                self.write_to_bytey_buffer(buffer)
+            }
+
+            #[inline]
+            fn write_to_bytey_buffer_be<__ByteySink: ::bytey::Sink>(&self, buffer: &mut __ByteySink) -> ::bytey::Result<()> {
+                self.write_to_bytey_buffer(buffer)
+            }
+        }
+
+        impl #impl_generics ::bytey::ByteBufferWrite for &#union_ident #ty_generics #where_clause {
+            #[inline]
+            fn write_to_bytey_buffer<__ByteySink: ::bytey::Sink>(&self, buffer: &mut __ByteySink) -> ::bytey::Result<()> {
+                (*self).write_to_bytey_buffer(buffer)
+            }
+
+            #[inline]
+            fn write_to_bytey_buffer_le<__ByteySink: ::bytey::Sink>(&self, buffer: &mut __ByteySink) -> ::bytey::Result<()> {
+                (*self).write_to_bytey_buffer(buffer)
+            }
+
+            #[inline]
+            fn write_to_bytey_buffer_be<__ByteySink: ::bytey::Sink>(&self, buffer: &mut __ByteySink) -> ::bytey::Result<()> {
+                (*self).write_to_bytey_buffer(buffer)
+            }
+        }
     }
 }
@@ -0,0 +1,52 @@
+/// A fixed byte order set with `#[bytey(endian = "...")]`, used instead of the byte order of the called method.
+#[derive(Copy, Clone)]
+pub enum Endian {
+    Native,
+    Little,
+    Big,
+}
+
+impl Endian {
+    pub fn from_expr(expr: &syn::Expr) -> syn::Result<Endian> {
+        let value = match expr {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(value),
+                ..
+            }) => value.value(),
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    expr,
+                    "Expected a string, e.g. #[bytey(endian = \"be\")]",
+                ));
+            }
+        };
+
+        match value.as_str() {
+            "native" => Ok(Endian::Native),
+            "le" => Ok(Endian::Little),
+            "be" => Ok(Endian::Big),
+            _ => Err(syn::Error::new_spanned(
+                expr,
+                "Unknown endian, expected one of \"be\", \"le\" or \"native\"",
+            )),
+        }
+    }
+
+    /// The `ByteBuffer` method reading in this byte order.
+    pub fn read_method(&self) -> &'static str {
+        match self {
+            Endian::Native => "read",
+            Endian::Little => "read_le",
+            Endian::Big => "read_be",
+        }
+    }
+
+    /// The `ByteBufferWrite` method writing in this byte order.
+    pub fn write_method(&self) -> &'static str {
+        match self {
+            Endian::Native => "write_to_bytey_buffer",
+            Endian::Little => "write_to_bytey_buffer_le",
+            Endian::Big => "write_to_bytey_buffer_be",
+        }
+    }
+}
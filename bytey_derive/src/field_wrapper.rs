@@ -1,4 +1,7 @@
+use crate::byte_buffer_read::ReadTarget;
+use crate::endian::Endian;
 use crate::symbols::*;
+use quote::{format_ident, quote};
 use syn::punctuated::Punctuated;
 use syn::{Meta, Token};
 
@@ -17,31 +20,192 @@ impl quote::ToTokens for FieldWrapper<'_> {
     }
 }
 
-pub fn is_skipped(field: &syn::Field) -> bool {
-    let mut ret = false;
+/// The `#[bytey(...)]` attributes of a single field.
+#[derive(Default)]
+pub struct FieldAttributes {
+    pub skip: bool,
+    pub default: Option<syn::Path>,
+    pub serialize_with: Option<syn::Path>,
+    pub deserialize_with: Option<syn::Path>,
+    pub endian: Option<Endian>,
+}
+
+impl FieldAttributes {
+    /// Parses the attributes of the field, `endian` is the byte order of the container if it has one.
+    pub fn from_field(field: &syn::Field, endian: Option<Endian>) -> syn::Result<FieldAttributes> {
+        let mut ret = FieldAttributes::default();
+        let mut field_endian = None;
+
+        for attr in &field.attrs {
+            for meta_item in get_bytey_meta_items(attr)? {
+                match &meta_item {
+                    Meta::Path(word) if word == SKIP => ret.skip = true,
+                    Meta::NameValue(name_value) if name_value.path == DEFAULT => {
+                        if ret.default.is_some() {
+                            return Err(syn::Error::new_spanned(
+                                &meta_item,
+                                "Duplicate default function for this field",
+                            ));
+                        }
+
+                        ret.default = Some(parse_path(&name_value.value)?);
+                    }
+                    Meta::NameValue(name_value) if name_value.path == WITH => {
+                        let module = parse_path(&name_value.value)?;
+
+                        set_once(
+                            &mut ret.serialize_with,
+                            syn::parse_quote!(#module::serialize),
+                            &meta_item,
+                        )?;
+                        set_once(
+                            &mut ret.deserialize_with,
+                            syn::parse_quote!(#module::deserialize),
+                            &meta_item,
+                        )?;
+                    }
+                    Meta::NameValue(name_value) if name_value.path == SERIALIZE_WITH => {
+                        let path = parse_path(&name_value.value)?;
 
-    for meta_item in field.attrs.iter().flat_map(get_bytey_meta_items) {
-        match meta_item {
-            Meta::Path(word) if word == SKIP => ret = true,
-            Meta::Path(_) => {
-                panic!("Unexpected field attribute found in bytey. Only skip is supported")
+                        set_once(&mut ret.serialize_with, path, &meta_item)?;
+                    }
+                    Meta::NameValue(name_value) if name_value.path == DESERIALIZE_WITH => {
+                        let path = parse_path(&name_value.value)?;
+
+                        set_once(&mut ret.deserialize_with, path, &meta_item)?;
+                    }
+                    Meta::NameValue(name_value) if name_value.path == ENDIAN => {
+                        field_endian = Some(Endian::from_expr(&name_value.value)?);
+                    }
+                    other => {
+                        return Err(syn::Error::new_spanned(
+                            other,
+                            "Unexpected field attribute found in bytey. Expected skip, default, with, serialize_with, deserialize_with or endian",
+                        ));
+                    }
+                }
             }
-            _ => {}
         }
+
+        if ret.skip && (ret.serialize_with.is_some() || ret.deserialize_with.is_some()) {
+            return Err(syn::Error::new_spanned(
+                field,
+                "A skipped field can not have a custom serialize or deserialize function",
+            ));
+        }
+
+        if ret.default.is_some() && !ret.skip {
+            return Err(syn::Error::new_spanned(
+                field,
+                "A default function is only used for skipped fields, add #[bytey(skip)]",
+            ));
+        }
+
+        if field_endian.is_some()
+            && (ret.skip || ret.serialize_with.is_some() || ret.deserialize_with.is_some())
+        {
+            return Err(syn::Error::new_spanned(
+                field,
+                "The endian of a skipped field or a field with custom functions has no effect",
+            ));
+        }
+
+        ret.endian = field_endian.or(endian);
+
+        Ok(ret)
     }
 
-    ret
-}
+    /// An expression reading the field using the given `ByteBuffer` read method, e.g. `read_le`.
+    /// A fixed endian of the field takes precedence over the method.
+    ///
+    /// For `ByteBufferReadRef` a field whose type has a lifetime borrows from the buffer, so it is read with the
+    /// matching `ByteBufferRef::read_ref` method instead, any other field is read as `ByteBufferRead`.
+    ///
+    /// Errors get the field added to their path, `type_name` is the struct or `Enum::Variant` the field is in.
+    pub fn read(
+        &self,
+        ty: &syn::Type,
+        target: ReadTarget,
+        method: &str,
+        type_name: &str,
+        field_name: &str,
+    ) -> proc_macro2::TokenStream {
+        let method = self.endian.map_or(method, |e| e.read_method());
+        let method = match target {
+            ReadTarget::Borrowed if has_lifetime(quote! { #ty }) => {
+                format_ident!("{}", method.replacen("read", "read_ref", 1))
+            }
+            _ => format_ident!("{}", method),
+        };
+        let context = quote! {
+            |error| ::bytey::ByteBufferError::with_field(error, #type_name, #field_name, buffer.offset())
+        };
 
-fn get_bytey_meta_items(attr: &syn::Attribute) -> Vec<syn::Meta> {
-    if attr.path() != BYTEY {
-        return Vec::new();
+        if let Some(path) = &self.default {
+            quote! { #path() }
+        } else if self.skip {
+            quote! { ::core::default::Default::default() }
+        } else if let Some(path) = &self.deserialize_with {
+            quote! { #path(buffer).map_err(#context)? }
+        } else {
+            quote! { buffer.#method::<#ty>().map_err(#context)? }
+        }
     }
 
-    match attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated) {
-        Ok(nested) => nested.into_iter().collect(),
-        Err(err) => {
-            panic!("error #[bytey(...)]: {err} ");
+    /// A statement writing the field using the given `ByteBufferWrite` method, e.g. `write_to_bytey_buffer_le`.
+    ///
+    /// `value` has to be a reference to the field. A fixed endian of the field takes precedence over the method.
+    pub fn write(&self, value: proc_macro2::TokenStream, method: &str) -> proc_macro2::TokenStream {
+        let method = format_ident!("{}", self.endian.map_or(method, |e| e.write_method()));
+
+        if let Some(path) = &self.serialize_with {
+            quote! { #path(#value, buffer)?; }
+        } else {
+            quote! { (#value).#method(buffer)?; }
         }
     }
 }
+
+/// Returns true if the tokens of a type contain a lifetime, e.g. `&'a str` or `Option<Cow<'a, [u8]>>`.
+fn has_lifetime(tokens: proc_macro2::TokenStream) -> bool {
+    tokens.into_iter().any(|token| match token {
+        proc_macro2::TokenTree::Punct(punct) => punct.as_char() == '\'',
+        proc_macro2::TokenTree::Group(group) => has_lifetime(group.stream()),
+        _ => false,
+    })
+}
+
+fn parse_path(expr: &syn::Expr) -> syn::Result<syn::Path> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(value),
+            ..
+        }) => value.parse(),
+        _ => Err(syn::Error::new_spanned(
+            expr,
+            "Expected a path as string, e.g. #[bytey(with = \"my_module\")]",
+        )),
+    }
+}
+
+fn set_once(slot: &mut Option<syn::Path>, path: syn::Path, meta_item: &Meta) -> syn::Result<()> {
+    if slot.is_some() {
+        return Err(syn::Error::new_spanned(
+            meta_item,
+            "Duplicate serialize or deserialize function for this field",
+        ));
+    }
+
+    *slot = Some(path);
+
+    Ok(())
+}
+
+pub fn get_bytey_meta_items(attr: &syn::Attribute) -> syn::Result<Vec<syn::Meta>> {
+    if attr.path() != BYTEY {
+        return Ok(Vec::new());
+    }
+
+    attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+        .map(|nested| nested.into_iter().collect())
+}
@@ -1,24 +1,169 @@
-#![feature(proc_macro_diagnostic)]
-
 mod byte_buffer_read;
 mod byte_buffer_write;
+mod endian;
 mod field_wrapper;
 mod source;
 mod symbols;
+mod tag;
 
+use byte_buffer_read::ReadTarget;
 use proc_macro::TokenStream;
 use syn::parse_macro_input;
 
+/// Derives `ByteBufferWrite` for a struct, enum or union.
+///
+/// Every field that isn't skipped has to implement `ByteBufferWrite` as well.
+/// Unit structs are written as zero bytes.
+///
+/// # Enums
+/// Every variant is prefixed with its id. The first variant has id 1 and every following variant
+/// gets the id of the previous variant + 1.
+///
+/// - `#[bytey(tag = "u8" | "u16" | "u32" | "u64" | "varint")]` on the enum sets the type used to store the id, the default is `u16`.
+///   `varint` stores the id as an unsigned LEB128 integer(see `bytey::VarU32`).
+/// - `#[bytey(id = N)]` on a variant sets its id, so ids stay stable when variants are reordered or removed.
+///
+/// When the enum has explicit discriminants, e.g. `Ping = 0x10`, they are used as ids and the ids follow the rust rules,
+/// so the first variant without a discriminant is 0. Only integer literals are supported as discriminant.
+/// If there is no tag attribute, `#[repr(u8)]`, `#[repr(u16)]`, `#[repr(u32)]` and `#[repr(u64)]` set the tag of an enum
+/// with explicit discriminants, enums without any keep the `u16` tag. Other integer reprs need a tag attribute.
+/// ```compile_fail
+/// use bytey::ByteBufferWrite;
+///
+/// #[derive(ByteBufferWrite)]
+/// #[repr(i8)]
+/// enum Signed {
+///     A = 1,
+/// }
+/// ```
+///
+/// Reading an id that doesn't belong to any variant returns `ByteBufferError::InvalidTag`.
+///
+/// Ids have to be unique and fit in the tag.
+/// ```compile_fail
+/// use bytey::ByteBufferWrite;
+///
+/// #[derive(ByteBufferWrite)]
+/// enum Duplicate {
+///     A,
+///     #[bytey(id = 1)]
+///     B,
+/// }
+/// ```
+/// ```compile_fail
+/// use bytey::ByteBufferWrite;
+///
+/// #[derive(ByteBufferWrite)]
+/// #[bytey(tag = "u8")]
+/// enum Overflow {
+///     #[bytey(id = 256)]
+///     A,
+/// }
+/// ```
+///
+/// # Unions
+/// Unions are only supported with `#[bytey(union_as = "bytes")]`, which copies the raw `size_of` bytes of the union
+/// regardless of the byte order. Every field has to implement `NoUninit` and be as large as the union, so no uninitialized
+/// bytes are copied. `NoUninit` is implemented for the primitive integers and floats and arrays of them.
+/// ```compile_fail
+/// use bytey::ByteBufferWrite;
+///
+/// #[derive(Clone, Copy)]
+/// #[repr(C)]
+/// struct Padded {
+///     a: u8,
+///     b: u16,
+/// }
+///
+/// // The byte between a and b is padding, so Padded isn't NoUninit.
+/// #[derive(ByteBufferWrite)]
+/// #[bytey(union_as = "bytes")]
+/// union Test {
+///     padded: Padded,
+///     int: u32,
+/// }
+/// ```
+///
+/// # Endianness
+/// `#[bytey(endian = "be" | "le" | "native")]` on a struct, enum or field fixes the byte order, so it is used
+/// whichever of the `write`, `write_le` and `write_be` methods was called. The endian of a field takes precedence
+/// over the endian of the container, and the endian of an enum also applies to its id.
+/// Nested values that derive bytey are written with the fixed byte order, they can fix their own with the attribute.
+///
+/// # Fields
+/// - `#[bytey(skip)]` excludes a field, this works for struct fields and enum variant fields.
+/// - `#[bytey(skip, default = "path")]` rebuilds a skipped field by calling `path()` instead of `Default::default()`.
+/// - `#[bytey(with = "module")]` uses `module::serialize` and `module::deserialize` instead of the
+///   `ByteBufferWrite` and `ByteBufferRead` impls of the field, so it works for foreign types.
+/// - `#[bytey(serialize_with = "path")]` and `#[bytey(deserialize_with = "path")]` set only one of the two functions.
+///
+/// The functions have the signatures `fn<S: bytey::Sink>(&T, &mut S) -> bytey::Result<()>` and
+/// `fn<S: bytey::Source>(&mut S) -> bytey::Result<T>`, they are called for every byte order.
+/// Functions taking a `&mut ByteBuffer`, as in bytey 0.4, have to be made generic.
+/// ```compile_fail
+/// use bytey::{ByteBuffer, ByteBufferWrite};
+///
+/// fn write_doubled(value: &u8, buffer: &mut ByteBuffer) -> bytey::Result<()> {
+///     buffer.write(value * 2)?;
+///
+///     Ok(())
+/// }
+///
+/// #[derive(ByteBufferWrite)]
+/// struct Test(#[bytey(serialize_with = "write_doubled")] u8);
+/// ```
 #[proc_macro_derive(ByteBufferWrite, attributes(bytey))]
 pub fn derive_byte_buffer_write(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as syn::DeriveInput);
 
-    byte_buffer_write::expand_derive_byte_buffer_write(&input).into()
+    byte_buffer_write::expand_derive_byte_buffer_write(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
 
+/// Derives `ByteBufferRead` for a struct, enum or union.
+///
+/// Accepts the same attributes as the `ByteBufferWrite` derive, the attributes on both derives have to match
+/// for a value to be read back. Skipped fields are set to their `Default` value, or to the result of
+/// the `default` function if one is given.
 #[proc_macro_derive(ByteBufferRead, attributes(bytey))]
 pub fn derive_byte_buffer_read(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as syn::DeriveInput);
 
-    byte_buffer_read::expand_derive_byte_buffer_read(&input).into()
+    byte_buffer_read::expand_derive_byte_buffer_read(&input, ReadTarget::Owned)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `ByteBufferReadRef` for a struct, enum or union, so it can be read from a `ByteBufferRef` without copying.
+///
+/// Fields whose type has a lifetime, like `&'a str`, `&'a [u8]` and `Option<Cow<'a, str>>`, borrow from the bytes of the
+/// buffer and have to implement `ByteBufferReadRef`. Every other field is read with its `ByteBufferRead` impl, as
+/// `ByteBufferRef` is a `Source`. Accepts the same attributes as the `ByteBufferRead` derive, `deserialize_with`
+/// functions get the `&mut ByteBufferRef`, so a function generic over `Source` works for both derives.
+///
+/// ```
+/// use bytey::{ByteBuffer, ByteBufferReadRef, ByteBufferWrite};
+///
+/// #[derive(ByteBufferWrite, ByteBufferReadRef, PartialEq, Debug)]
+/// struct Packet<'a> {
+///     id: u16,
+///     name: &'a str,
+/// }
+///
+/// let mut buffer = ByteBuffer::new().unwrap();
+///
+/// buffer.write(Packet { id: 1, name: "bytey" }).unwrap();
+/// buffer.move_cursor(0).unwrap();
+///
+/// let packet: Packet = buffer.reader().read_ref().unwrap();
+/// assert_eq!(packet, Packet { id: 1, name: "bytey" });
+/// ```
+#[proc_macro_derive(ByteBufferReadRef, attributes(bytey))]
+pub fn derive_byte_buffer_read_ref(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+
+    byte_buffer_read::expand_derive_byte_buffer_read(&input, ReadTarget::Borrowed)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
@@ -1,59 +1,337 @@
+use crate::endian::Endian;
+use crate::field_wrapper::get_bytey_meta_items;
+use crate::symbols::*;
+use crate::tag::Tag;
+use std::collections::HashMap;
+use syn::Meta;
+
 pub struct StructSource<'a> {
     pub ident: &'a syn::Ident,
     pub fields: &'a syn::Fields,
     pub generics: &'a syn::Generics,
+    pub endian: Option<Endian>,
 }
 
 pub struct EnumSource<'a> {
     pub ident: &'a syn::Ident,
     pub variants: Vec<EnumVariant<'a>>,
     pub generics: &'a syn::Generics,
+    pub tag: Tag,
+    pub endian: Option<Endian>,
+}
+
+pub struct UnionSource<'a> {
+    pub ident: &'a syn::Ident,
+    pub fields: &'a syn::FieldsNamed,
+    pub generics: &'a syn::Generics,
 }
 
 pub struct EnumVariant<'a> {
     pub ident: &'a syn::Ident,
     pub fields: &'a syn::Fields,
+    pub id: u64,
 }
 
 impl StructSource<'_> {
-    pub fn from_input(input: &syn::DeriveInput) -> StructSource {
+    pub fn from_input(input: &syn::DeriveInput) -> syn::Result<StructSource<'_>> {
         let ident = &input.ident;
         let fields = match &input.data {
             syn::Data::Struct(data) => &data.fields,
             _ => panic!("StructSource::from_input called on a non-struct"),
         };
         let generics = &input.generics;
+        let mut endian = None;
+
+        for attr in &input.attrs {
+            for meta_item in get_bytey_meta_items(attr)? {
+                match meta_item {
+                    Meta::NameValue(name_value) if name_value.path == ENDIAN => {
+                        endian = Some(Endian::from_expr(&name_value.value)?);
+                    }
+                    other => {
+                        return Err(syn::Error::new_spanned(
+                            other,
+                            "Unexpected struct attribute found in bytey. Only endian is supported",
+                        ));
+                    }
+                }
+            }
+        }
 
-        StructSource {
+        Ok(StructSource {
             ident,
             fields,
             generics,
-        }
+            endian,
+        })
     }
 }
 
 impl EnumSource<'_> {
-    pub fn from_input(input: &syn::DeriveInput) -> EnumSource {
+    pub fn from_input(input: &syn::DeriveInput) -> syn::Result<EnumSource<'_>> {
         let ident = &input.ident;
         let mut vars: Vec<EnumVariant> = Vec::new();
         let generics = &input.generics;
+        let mut tag = None;
+        let mut endian = None;
+
+        for attr in &input.attrs {
+            for meta_item in get_bytey_meta_items(attr)? {
+                match meta_item {
+                    Meta::NameValue(name_value) if name_value.path == TAG => {
+                        tag = Some(Tag::from_expr(&name_value.value)?);
+                    }
+                    Meta::NameValue(name_value) if name_value.path == ENDIAN => {
+                        endian = Some(Endian::from_expr(&name_value.value)?);
+                    }
+                    other => {
+                        return Err(syn::Error::new_spanned(
+                            other,
+                            "Unexpected enum attribute found in bytey. Only tag and endian are supported",
+                        ));
+                    }
+                }
+            }
+        }
+
+        // The repr only sets the tag of enums with explicit discriminants, so enums that merely have a repr keep
+        // the u16 tag they always had.
+        let has_discriminant = match &input.data {
+            syn::Data::Enum(data) => data.variants.iter().any(|v| v.discriminant.is_some()),
+            _ => false,
+        };
+        let tag = match tag {
+            Some(tag) => tag,
+            None if has_discriminant => repr_tag(&input.attrs)?.unwrap_or(Tag::U16),
+            None => Tag::U16,
+        };
 
         match &input.data {
             syn::Data::Enum(syn::DataEnum { variants, .. }) => {
+                let mut used: HashMap<u64, &syn::Ident> = HashMap::new();
+                // Follow the rust rules for discriminants once any are given, so the ids match `Enum::Variant as u8`.
+                let mut next_id = if has_discriminant {
+                    Some(0u64)
+                } else {
+                    Some(1u64)
+                };
+
                 for variant in variants {
                     let ident = &variant.ident;
                     let fields = &variant.fields;
 
-                    vars.push(EnumVariant { ident, fields });
+                    let id = match variant_id(variant)? {
+                        Some(id) => id,
+                        None => next_id.ok_or_else(|| {
+                            syn::Error::new_spanned(
+                                ident,
+                                "The id of this variant overflows, give it an explicit #[bytey(id = ...)]",
+                            )
+                        })?,
+                    };
+
+                    if id > tag.max() {
+                        return Err(syn::Error::new_spanned(
+                            ident,
+                            format!(
+                                "The id {id} of this variant does not fit in the {} tag",
+                                tag.name()
+                            ),
+                        ));
+                    }
+
+                    if let Some(other) = used.insert(id, ident) {
+                        return Err(syn::Error::new_spanned(
+                            ident,
+                            format!("The id {id} of this variant is already used by {other}"),
+                        ));
+                    }
+
+                    next_id = id.checked_add(1);
+                    vars.push(EnumVariant { ident, fields, id });
                 }
             }
-            _ => panic!("StructSource::from_input called on a non-struct"),
+            _ => panic!("EnumSource::from_input called on a non-enum"),
         }
 
-        EnumSource {
+        Ok(EnumSource {
             ident,
             variants: vars,
             generics,
+            tag,
+            endian,
+        })
+    }
+}
+
+impl UnionSource<'_> {
+    pub fn from_input(input: &syn::DeriveInput) -> syn::Result<UnionSource<'_>> {
+        let ident = &input.ident;
+        let fields = match &input.data {
+            syn::Data::Union(data) => &data.fields,
+            _ => panic!("UnionSource::from_input called on a non-union"),
+        };
+        let generics = &input.generics;
+        let mut as_bytes = false;
+
+        for attr in &input.attrs {
+            for meta_item in get_bytey_meta_items(attr)? {
+                match meta_item {
+                    Meta::NameValue(name_value) if name_value.path == UNION_AS => {
+                        match &name_value.value {
+                            syn::Expr::Lit(syn::ExprLit {
+                                lit: syn::Lit::Str(value),
+                                ..
+                            }) if value.value() == "bytes" => as_bytes = true,
+                            other => {
+                                return Err(syn::Error::new_spanned(
+                                    other,
+                                    "Unknown union encoding, expected \"bytes\"",
+                                ));
+                            }
+                        }
+                    }
+                    other => {
+                        return Err(syn::Error::new_spanned(
+                            other,
+                            "Unexpected union attribute found in bytey. Only union_as is supported",
+                        ));
+                    }
+                }
+            }
         }
+
+        if !as_bytes {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "Unions are only supported with #[bytey(union_as = \"bytes\")]",
+            ));
+        }
+
+        for field in &fields.named {
+            if let Some(meta_item) = field
+                .attrs
+                .iter()
+                .map(get_bytey_meta_items)
+                .collect::<syn::Result<Vec<_>>>()?
+                .into_iter()
+                .flatten()
+                .next()
+            {
+                return Err(syn::Error::new_spanned(
+                    meta_item,
+                    "Union fields don't support bytey attributes",
+                ));
+            }
+        }
+
+        Ok(UnionSource {
+            ident,
+            fields,
+            generics,
+        })
+    }
+
+    /// Compile time checks that every field is `NoUninit` and as large as the union, so every byte of the union
+    /// is initialized and any bytes are a valid value of every field, which makes copying the raw bytes sound.
+    pub fn assertions(&self) -> proc_macro2::TokenStream {
+        let field_tys: Vec<&syn::Type> = self.fields.named.iter().map(|f| &f.ty).collect();
+
+        quote::quote! {
+            fn assert_no_uninit<T: ::bytey::NoUninit>() {}
+            #(
+                assert_no_uninit::<#field_tys>();
+                const {
+                    ::core::assert!(
+                        ::core::mem::size_of::<#field_tys>() == ::core::mem::size_of::<Self>(),
+                        "union_as = \"bytes\" requires every field to be as large as the union"
+                    )
+                };
+            )*
+        }
+    }
+}
+
+fn variant_id(variant: &syn::Variant) -> syn::Result<Option<u64>> {
+    let mut ret = None;
+
+    for attr in &variant.attrs {
+        for meta_item in get_bytey_meta_items(attr)? {
+            match meta_item {
+                Meta::NameValue(name_value) if name_value.path == ID => match &name_value.value {
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Int(id),
+                        ..
+                    }) => ret = Some(id.base10_parse::<u64>()?),
+                    other => {
+                        return Err(syn::Error::new_spanned(
+                            other,
+                            "Expected an integer, e.g. #[bytey(id = 1)]",
+                        ));
+                    }
+                },
+                other => {
+                    return Err(syn::Error::new_spanned(
+                        other,
+                        "Unexpected variant attribute found in bytey. Only id is supported",
+                    ));
+                }
+            }
+        }
+    }
+
+    if ret.is_none()
+        && let Some((_, expr)) = &variant.discriminant
+    {
+        ret = Some(discriminant(expr)?);
+    }
+
+    Ok(ret)
+}
+
+fn discriminant(expr: &syn::Expr) -> syn::Result<u64> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(id),
+            ..
+        }) => id.base10_parse::<u64>(),
+        syn::Expr::Group(syn::ExprGroup { expr, .. })
+        | syn::Expr::Paren(syn::ExprParen { expr, .. }) => discriminant(expr),
+        _ => Err(syn::Error::new_spanned(
+            expr,
+            "Only positive integer literals are supported as discriminant, use #[bytey(id = ...)] instead",
+        )),
+    }
+}
+
+/// Returns the tag matching the `#[repr(...)]` of the enum, if any.
+fn repr_tag(attrs: &[syn::Attribute]) -> syn::Result<Option<Tag>> {
+    let mut ret = None;
+
+    for attr in attrs.iter().filter(|attr| attr.path() == REPR) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path == U8 {
+                ret = Some(Tag::U8);
+            } else if meta.path == U16 {
+                ret = Some(Tag::U16);
+            } else if meta.path == U32 {
+                ret = Some(Tag::U32);
+            } else if meta.path == U64 {
+                ret = Some(Tag::U64);
+            } else if UNSUPPORTED_REPRS.iter().any(|repr| meta.path == *repr) {
+                return Err(meta.error(
+                    "Only #[repr(u8 | u16 | u32 | u64)] can be used as tag, set the tag with #[bytey(tag = \"...\")] instead",
+                ));
+            } else if meta.input.peek(syn::token::Paren) {
+                // e.g. align(4), the content isn't relevant to the tag.
+                let content;
+                syn::parenthesized!(content in meta.input);
+                content.parse::<proc_macro2::TokenStream>()?;
+            }
+
+            Ok(())
+        })?;
     }
+
+    Ok(ret)
 }
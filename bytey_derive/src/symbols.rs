@@ -6,6 +6,30 @@ pub struct Symbol(&'static str);
 
 pub const BYTEY: Symbol = Symbol("bytey");
 pub const SKIP: Symbol = Symbol("skip");
+pub const DEFAULT: Symbol = Symbol("default");
+pub const WITH: Symbol = Symbol("with");
+pub const SERIALIZE_WITH: Symbol = Symbol("serialize_with");
+pub const DESERIALIZE_WITH: Symbol = Symbol("deserialize_with");
+pub const TAG: Symbol = Symbol("tag");
+pub const ID: Symbol = Symbol("id");
+pub const ENDIAN: Symbol = Symbol("endian");
+pub const UNION_AS: Symbol = Symbol("union_as");
+pub const REPR: Symbol = Symbol("repr");
+pub const U8: Symbol = Symbol("u8");
+pub const U16: Symbol = Symbol("u16");
+pub const U32: Symbol = Symbol("u32");
+pub const U64: Symbol = Symbol("u64");
+/// Integer reprs that don't map to a tag.
+pub const UNSUPPORTED_REPRS: [Symbol; 8] = [
+    Symbol("u128"),
+    Symbol("usize"),
+    Symbol("i8"),
+    Symbol("i16"),
+    Symbol("i32"),
+    Symbol("i64"),
+    Symbol("i128"),
+    Symbol("isize"),
+];
 
 impl PartialEq<Symbol> for Ident {
     fn eq(&self, word: &Symbol) -> bool {
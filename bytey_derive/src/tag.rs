@@ -0,0 +1,91 @@
+use quote::quote;
+
+/// The integer type used to store the variant id of an enum.
+#[derive(Copy, Clone)]
+pub enum Tag {
+    U8,
+    U16,
+    U32,
+    U64,
+    VarInt,
+}
+
+impl Tag {
+    pub fn from_expr(expr: &syn::Expr) -> syn::Result<Tag> {
+        let value = match expr {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(value),
+                ..
+            }) => value.value(),
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    expr,
+                    "Expected a string, e.g. #[bytey(tag = \"u8\")]",
+                ));
+            }
+        };
+
+        match value.as_str() {
+            "u8" => Ok(Tag::U8),
+            "u16" => Ok(Tag::U16),
+            "u32" => Ok(Tag::U32),
+            "u64" => Ok(Tag::U64),
+            "varint" => Ok(Tag::VarInt),
+            _ => Err(syn::Error::new_spanned(
+                expr,
+                "Unknown tag, expected one of \"u8\", \"u16\", \"u32\", \"u64\" or \"varint\"",
+            )),
+        }
+    }
+
+    /// The largest variant id that can be stored with this tag.
+    pub fn max(&self) -> u64 {
+        match self {
+            Tag::U8 => u8::MAX.into(),
+            Tag::U16 => u16::MAX.into(),
+            Tag::U32 | Tag::VarInt => u32::MAX.into(),
+            Tag::U64 => u64::MAX,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Tag::U8 => "u8",
+            Tag::U16 => "u16",
+            Tag::U32 => "u32",
+            Tag::U64 => "u64",
+            Tag::VarInt => "varint",
+        }
+    }
+
+    /// A literal of the id that can be used as a match pattern against [`read`](Self::read).
+    pub fn literal(&self, id: u64) -> syn::LitInt {
+        let suffix = match self {
+            Tag::VarInt => "u32",
+            tag => tag.name(),
+        };
+
+        syn::LitInt::new(&format!("{id}{suffix}"), proc_macro2::Span::call_site())
+    }
+
+    /// An expression implementing `ByteBufferWrite` that stores the given id.
+    pub fn value(&self, id: u64) -> proc_macro2::TokenStream {
+        let literal = self.literal(id);
+
+        match self {
+            Tag::VarInt => quote! { ::bytey::VarU32(#literal) },
+            _ => quote! { #literal },
+        }
+    }
+
+    /// An expression reading the id using the given `ByteBuffer` read method.
+    pub fn read(&self, method: syn::Ident) -> proc_macro2::TokenStream {
+        match self {
+            Tag::U8 => quote! { buffer.#method::<u8>()? },
+            Tag::U16 => quote! { buffer.#method::<u16>()? },
+            Tag::U32 => quote! { buffer.#method::<u32>()? },
+            Tag::U64 => quote! { buffer.#method::<u64>()? },
+            Tag::VarInt => quote! { buffer.#method::<::bytey::VarU32>()?.0 },
+        }
+    }
+}
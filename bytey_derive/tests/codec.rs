@@ -0,0 +1,179 @@
+use bytey::{ByteBufferError, ByteyCodec, DecodeLimits, LengthPrefix};
+use bytey_derive::{ByteBufferRead, ByteBufferWrite};
+use futures_util::{SinkExt, StreamExt};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio_util::codec::{FramedRead, FramedWrite};
+
+#[derive(ByteBufferWrite, ByteBufferRead, PartialEq, Debug)]
+enum Message {
+    Login { name: String },
+    Move(i32, i32),
+    Logout,
+}
+
+#[tokio::test]
+async fn test_codec_framed() {
+    let (client, server) = tokio::io::duplex(16);
+    let messages = vec![
+        Message::Login {
+            name: String::from("a rather long name that spans several writes"),
+        },
+        Message::Move(-3, 4),
+        Message::Logout,
+    ];
+
+    let writer = async {
+        let mut framed = FramedWrite::new(client, ByteyCodec::<Message>::new());
+
+        for message in &messages {
+            framed.send(message).await.unwrap();
+        }
+    };
+
+    let reader = async {
+        let mut framed = FramedRead::new(server, ByteyCodec::<Message>::new());
+        let mut received = Vec::new();
+
+        while let Some(message) = framed.next().await {
+            received.push(message.unwrap());
+        }
+
+        received
+    };
+
+    let ((), received) = tokio::join!(writer, reader);
+
+    assert_eq!(received, messages);
+}
+
+#[tokio::test]
+async fn test_codec_partial_frame() {
+    let (mut client, server) = tokio::io::duplex(64);
+    let mut framed = FramedRead::new(server, ByteyCodec::<u16>::new());
+
+    client.write_all(&[0, 0, 0]).await.unwrap();
+    client.write_all(&[2, 1]).await.unwrap();
+
+    // Only half of the frame has been written, so no value is available yet.
+    assert!(futures_util::FutureExt::now_or_never(framed.next()).is_none());
+
+    client.write_all(&[2]).await.unwrap();
+    drop(client);
+
+    assert_eq!(framed.next().await.unwrap().unwrap(), 0x0102);
+    assert!(framed.next().await.is_none());
+}
+
+#[tokio::test]
+async fn test_codec_max_frame_length() {
+    let (mut client, server) = tokio::io::duplex(64);
+    let mut codec = ByteyCodec::<Vec<u8>>::new();
+
+    codec.set_max_frame_length(4);
+
+    let mut framed = FramedRead::new(server, codec);
+
+    client.write_all(&[0, 0, 0, 12]).await.unwrap();
+
+    assert_eq!(
+        framed.next().await.unwrap(),
+        Err(ByteBufferError::LengthOverflow {
+            length: 12,
+            target: "the max frame length"
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_codec_decode_limits() {
+    let (mut client, server) = tokio::io::duplex(64);
+    let mut limits = DecodeLimits::new();
+
+    limits.set_max_string_length(3);
+
+    let mut framed = FramedRead::new(server, ByteyCodec::<String>::with_decode_limits(limits));
+
+    client
+        .write_all(&[0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 4])
+        .await
+        .unwrap();
+    client.write_all(b"abcd").await.unwrap();
+
+    assert_eq!(
+        framed.next().await.unwrap(),
+        Err(ByteBufferError::StringTooLong { length: 4, max: 3 })
+    );
+}
+
+#[tokio::test]
+async fn test_codec_trailing_bytes() {
+    let (mut client, server) = tokio::io::duplex(64);
+    let mut framed = FramedRead::new(server, ByteyCodec::<u16>::new());
+
+    client.write_all(&[0, 0, 0, 3, 1, 2, 3]).await.unwrap();
+
+    assert_eq!(
+        framed.next().await.unwrap(),
+        Err(ByteBufferError::TrailingBytes { remaining: 1 })
+    );
+}
+
+#[tokio::test]
+async fn test_codec_varint_prefix() {
+    let (client, mut server) = tokio::io::duplex(512);
+    let mut codec = ByteyCodec::<String>::new();
+
+    codec.set_length_prefix(LengthPrefix::VarInt);
+
+    let mut writer = FramedWrite::new(client, codec.clone());
+    let value = "a".repeat(200);
+
+    writer.send(&value).await.unwrap();
+
+    // 8 bytes of string length and 200 bytes of text take a 2 byte varint.
+    let mut header = [0; 2];
+
+    server.read_exact(&mut header).await.unwrap();
+    assert_eq!(header, [0xD0, 0x01]);
+
+    let mut body = vec![0; 208];
+
+    server.read_exact(&mut body).await.unwrap();
+    drop(writer);
+
+    let mut frame = header.to_vec();
+
+    frame.extend_from_slice(&body);
+
+    let mut reader = FramedRead::new(&frame[..], codec);
+
+    assert_eq!(reader.next().await.unwrap().unwrap(), value);
+    assert!(reader.next().await.is_none());
+}
+
+#[tokio::test]
+async fn test_codec_encode_error() {
+    let (client, mut server) = tokio::io::duplex(64);
+    let mut codec = ByteyCodec::<Vec<u8>>::new();
+
+    codec.set_max_frame_length(9);
+
+    let mut framed = FramedWrite::new(client, codec);
+
+    assert_eq!(
+        framed.send(vec![0; 2]).await,
+        Err(ByteBufferError::LengthOverflow {
+            length: 10,
+            target: "the max frame length"
+        })
+    );
+
+    // Nothing of the failed frame was written.
+    framed.send(vec![7]).await.unwrap();
+    drop(framed);
+
+    let mut bytes = Vec::new();
+
+    server.read_to_end(&mut bytes).await.unwrap();
+    assert_eq!(bytes, [0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 1, 7]);
+}
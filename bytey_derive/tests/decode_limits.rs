@@ -0,0 +1,77 @@
+use bytey::{ByteBuffer, ByteBufferError, DecodeLimits};
+use bytey_derive::{ByteBufferRead, ByteBufferWrite};
+
+#[derive(ByteBufferWrite, ByteBufferRead, PartialEq, Debug)]
+enum List {
+    Nil,
+    Cons(u8, Box<List>),
+}
+
+#[derive(ByteBufferWrite, ByteBufferRead, PartialEq, Debug)]
+struct Tree {
+    value: u8,
+    children: Vec<Tree>,
+}
+
+fn list(length: u8) -> List {
+    (0..length).fold(List::Nil, |tail, value| List::Cons(value, Box::new(tail)))
+}
+
+#[test]
+fn test_recursive_enum_depth() {
+    let mut buffer = ByteBuffer::new().unwrap();
+    let mut limits = DecodeLimits::new();
+
+    // Every element is a derived enum and a box deep.
+    limits.set_max_depth(21);
+    buffer.set_decode_limits(limits);
+
+    buffer.write(list(10)).unwrap();
+    buffer.write(list(11)).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<List>().unwrap(), list(10));
+    assert_eq!(
+        buffer.read::<List>().unwrap_err().root(),
+        &ByteBufferError::DepthLimit { max: 21 }
+    );
+}
+
+#[test]
+fn test_recursive_struct_depth() {
+    let leaf = Tree {
+        value: 2,
+        children: Vec::new(),
+    };
+    let tree = Tree {
+        value: 1,
+        children: vec![Tree {
+            value: 3,
+            children: vec![leaf],
+        }],
+    };
+
+    let mut buffer = ByteBuffer::new().unwrap();
+    let mut limits = DecodeLimits::new();
+
+    limits.set_max_depth(5);
+    buffer.set_decode_limits(limits);
+
+    buffer.write(&tree).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    // 3 trees and the 2 vectors they are in, the empty vector of the leaf isn't nested.
+    assert_eq!(buffer.read::<Tree>().unwrap(), tree);
+
+    limits.set_max_depth(4);
+    buffer.set_decode_limits(limits);
+    buffer.move_cursor(0).unwrap();
+
+    let error = buffer.read::<Tree>().unwrap_err();
+
+    assert_eq!(error.root(), &ByteBufferError::DepthLimit { max: 4 });
+    assert_eq!(
+        error.context().unwrap().path().to_string(),
+        "Tree.children[0].children[0]"
+    );
+}
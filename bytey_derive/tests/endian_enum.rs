@@ -0,0 +1,190 @@
+use bytey::ByteBuffer;
+use bytey_derive::{ByteBufferRead, ByteBufferWrite};
+
+#[derive(ByteBufferWrite, ByteBufferRead, PartialEq, Debug, Clone)]
+enum Test {
+    Named { a: u16, b: u8, c: i64, d: u32 },
+    Unnamed(u16, u8, i64, u32),
+    Unit,
+}
+
+#[derive(ByteBufferWrite, ByteBufferRead, PartialEq, Debug, Clone)]
+enum Outer {
+    Empty,
+    Inner(Test),
+    Wrapped { inner: Test, tail: u16 },
+}
+
+fn values() -> Vec<Test> {
+    vec![
+        Test::Named {
+            a: 0x0102,
+            b: 0x03,
+            c: -255,
+            d: 0x0405_0607,
+        },
+        Test::Unnamed(0x0102, 0x03, -255, 0x0405_0607),
+        Test::Unit,
+    ]
+}
+
+#[test]
+fn test_enum_write_read_ne() {
+    for val in values() {
+        let mut buffer = ByteBuffer::new().unwrap();
+
+        buffer.write(&val).unwrap();
+        buffer.move_cursor(0).unwrap();
+
+        assert_eq!(val, buffer.read::<Test>().unwrap());
+        assert_eq!(buffer.cursor(), buffer.length());
+    }
+}
+
+#[test]
+fn test_enum_write_read_le() {
+    for val in values() {
+        let mut buffer = ByteBuffer::new().unwrap();
+
+        buffer.write_le(&val).unwrap();
+        buffer.move_cursor(0).unwrap();
+
+        assert_eq!(val, buffer.read_le::<Test>().unwrap());
+        assert_eq!(buffer.cursor(), buffer.length());
+    }
+}
+
+#[test]
+fn test_enum_write_read_be() {
+    for val in values() {
+        let mut buffer = ByteBuffer::new().unwrap();
+
+        buffer.write_be(&val).unwrap();
+        buffer.move_cursor(0).unwrap();
+
+        assert_eq!(val, buffer.read_be::<Test>().unwrap());
+        assert_eq!(buffer.cursor(), buffer.length());
+    }
+}
+
+#[test]
+fn test_enum_write_le_bytes() {
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write_le(&values()[0]).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        [
+            1, 0, /* u16 for variant named id: 1 */
+            2, 1, /* u16: 0x0102 */
+            3, /* u8: 0x03 */
+            1, 255, 255, 255, 255, 255, 255, 255, /* i64: -255 */
+            7, 6, 5, 4 /* u32: 0x04050607 */
+        ],
+        buffer.read_slice(17).unwrap()
+    );
+
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write_le(Test::Unit).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!([3, 0], buffer.read_slice(2).unwrap());
+}
+
+#[test]
+fn test_enum_write_be_bytes() {
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write_be(&values()[1]).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        [
+            0, 2, /* u16 for variant unnamed id: 2 */
+            1, 2, /* u16: 0x0102 */
+            3, /* u8: 0x03 */
+            255, 255, 255, 255, 255, 255, 255, 1, /* i64: -255 */
+            4, 5, 6, 7 /* u32: 0x04050607 */
+        ],
+        buffer.read_slice(17).unwrap()
+    );
+
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write_be(Test::Unit).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!([0, 3], buffer.read_slice(2).unwrap());
+}
+
+#[test]
+fn test_enum_read_le_from_bytes() {
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer
+        .write_slice(&[
+            2, 0, 2, 1, 3, 1, 255, 255, 255, 255, 255, 255, 255, 7, 6, 5, 4,
+        ])
+        .unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(values()[1], buffer.read_le::<Test>().unwrap());
+}
+
+#[test]
+fn test_enum_read_be_from_bytes() {
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer
+        .write_slice(&[
+            0, 1, 1, 2, 3, 255, 255, 255, 255, 255, 255, 255, 1, 4, 5, 6, 7,
+        ])
+        .unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(values()[0], buffer.read_be::<Test>().unwrap());
+}
+
+#[test]
+fn test_enum_mismatched_endian() {
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write_be(Test::Unit).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert!(buffer.read_le::<Test>().is_err());
+}
+
+#[test]
+fn test_nested_enum_write_read() {
+    let outer = [
+        Outer::Empty,
+        Outer::Inner(values()[0].clone()),
+        Outer::Wrapped {
+            inner: values()[1].clone(),
+            tail: 0xABCD,
+        },
+    ];
+
+    for val in outer {
+        let mut buffer = ByteBuffer::new().unwrap();
+
+        buffer.write_le(&val).unwrap();
+        buffer.move_cursor(0).unwrap();
+        assert_eq!(val, buffer.read_le::<Outer>().unwrap());
+
+        let mut buffer = ByteBuffer::new().unwrap();
+
+        buffer.write_be(&val).unwrap();
+        buffer.move_cursor(0).unwrap();
+        assert_eq!(val, buffer.read_be::<Outer>().unwrap());
+
+        let mut buffer = ByteBuffer::new().unwrap();
+
+        buffer.write(&val).unwrap();
+        buffer.move_cursor(0).unwrap();
+        assert_eq!(val, buffer.read::<Outer>().unwrap());
+    }
+}
@@ -0,0 +1,100 @@
+use bytey::ByteBuffer;
+use bytey_derive::{ByteBufferRead, ByteBufferWrite};
+
+#[derive(ByteBufferWrite, ByteBufferRead, PartialEq, Debug)]
+#[bytey(endian = "be")]
+struct Header {
+    length: u16,
+    #[bytey(endian = "le")]
+    flags: u16,
+}
+
+#[derive(ByteBufferWrite, ByteBufferRead, PartialEq, Debug)]
+struct Message {
+    header: Header,
+    #[bytey(endian = "be")]
+    sequence: u32,
+    payload: u16,
+}
+
+#[test]
+fn test_struct_endian_ignores_method() {
+    let header = Header {
+        length: 0x0102,
+        flags: 0x0304,
+    };
+
+    for write in [
+        ByteBuffer::write::<&Header>,
+        ByteBuffer::write_le::<&Header>,
+        ByteBuffer::write_be::<&Header>,
+    ] {
+        let mut buffer = ByteBuffer::new().unwrap();
+
+        write(&mut buffer, &header).unwrap();
+        buffer.move_cursor(0).unwrap();
+
+        assert_eq!([1, 2, 4, 3], buffer.read_slice(4).unwrap());
+
+        buffer.move_cursor(0).unwrap();
+        assert_eq!(header, buffer.read::<Header>().unwrap());
+
+        buffer.move_cursor(0).unwrap();
+        assert_eq!(header, buffer.read_le::<Header>().unwrap());
+
+        buffer.move_cursor(0).unwrap();
+        assert_eq!(header, buffer.read_be::<Header>().unwrap());
+    }
+}
+
+#[test]
+fn test_field_endian() {
+    let val = Message {
+        header: Header {
+            length: 0x0102,
+            flags: 0x0304,
+        },
+        sequence: 0x05060708,
+        payload: 0x090A,
+    };
+
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write_le(&val).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        [1, 2, 4, 3, 5, 6, 7, 8, 0x0A, 0x09],
+        buffer.read_slice(10).unwrap()
+    );
+
+    buffer.move_cursor(0).unwrap();
+    assert_eq!(val, buffer.read_le::<Message>().unwrap());
+
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write(&val).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(val, buffer.read::<Message>().unwrap());
+}
+
+#[test]
+fn test_enum_endian() {
+    #[derive(ByteBufferWrite, ByteBufferRead, PartialEq, Debug)]
+    #[bytey(endian = "le")]
+    enum Test {
+        A(u16, #[bytey(endian = "be")] u16),
+    }
+
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write_be(Test::A(0x0102, 0x0304)).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!([1, 0, 2, 1, 3, 4], buffer.read_slice(6).unwrap());
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(Test::A(0x0102, 0x0304), buffer.read_be::<Test>().unwrap());
+}
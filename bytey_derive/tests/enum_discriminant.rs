@@ -0,0 +1,163 @@
+use bytey::{ByteBuffer, ByteBufferError};
+use bytey_derive::{ByteBufferRead, ByteBufferWrite};
+
+#[test]
+fn test_enum_explicit_discriminants() {
+    #[derive(ByteBufferWrite, ByteBufferRead, PartialEq, Debug, Clone, Copy)]
+    enum Op {
+        Ping = 0x10,
+        Pong = 0x11,
+        Close = 0x20,
+        Next,
+    }
+
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    for op in [Op::Ping, Op::Pong, Op::Close, Op::Next] {
+        buffer.write_be(op).unwrap();
+    }
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        [0x00, 0x10, 0x00, 0x11, 0x00, 0x20, 0x00, 0x21],
+        buffer.read_slice(8).unwrap()
+    );
+
+    buffer.move_cursor(0).unwrap();
+
+    for op in [Op::Ping, Op::Pong, Op::Close, Op::Next] {
+        assert_eq!(op, buffer.read_be::<Op>().unwrap());
+    }
+}
+
+#[test]
+fn test_enum_implicit_discriminants_start_at_zero() {
+    #[derive(ByteBufferWrite, ByteBufferRead, PartialEq, Debug, Clone, Copy)]
+    #[repr(u8)]
+    enum Test {
+        A,
+        B = 5,
+        C,
+    }
+
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    for val in [Test::A, Test::B, Test::C] {
+        buffer.write(val).unwrap();
+    }
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        [Test::A as u8, Test::B as u8, Test::C as u8],
+        buffer.read_slice(3).unwrap()
+    );
+}
+
+#[test]
+fn test_enum_repr_tag() {
+    #[derive(ByteBufferWrite, ByteBufferRead, PartialEq, Debug)]
+    #[repr(u32)]
+    enum Test {
+        A = 0x01020304,
+    }
+
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write_be(Test::A).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!([1, 2, 3, 4], buffer.read_slice(4).unwrap());
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(Test::A, buffer.read_be::<Test>().unwrap());
+}
+
+#[test]
+fn test_enum_tag_overrides_repr() {
+    #[derive(ByteBufferWrite, PartialEq, Debug)]
+    #[repr(u32)]
+    #[bytey(tag = "u8")]
+    enum Test {
+        #[bytey(id = 3)]
+        A = 0x01020304,
+    }
+
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write(Test::A).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.length(), 1);
+    assert_eq!([3], buffer.read_slice(1).unwrap());
+}
+
+#[test]
+fn test_enum_unknown_discriminant() {
+    #[derive(ByteBufferRead, PartialEq, Debug)]
+    #[repr(u8)]
+    enum Op {
+        Ping = 0x10,
+        Pong = 0x11,
+    }
+
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write(0x12u8).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        buffer.read::<Op>().unwrap_err(),
+        ByteBufferError::InvalidTag {
+            type_name: "Op",
+            tag: 0x12
+        }
+    );
+}
+
+#[test]
+fn test_enum_repr_without_discriminants() {
+    // Without explicit discriminants the repr doesn't change the tag.
+    #[derive(ByteBufferWrite, ByteBufferRead, PartialEq, Debug)]
+    #[repr(u8)]
+    enum Test {
+        A,
+        B,
+    }
+
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write_be(Test::B).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!([0, 2], buffer.read_slice(2).unwrap());
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(Test::B, buffer.read_be::<Test>().unwrap());
+}
+
+#[test]
+fn test_enum_repr_u64_tag() {
+    #[derive(ByteBufferWrite, ByteBufferRead, PartialEq, Debug)]
+    #[repr(u64)]
+    enum Test {
+        A = 0x0102030405060708,
+        B,
+    }
+
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write_be(Test::A).unwrap();
+    buffer.write_be(Test::B).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!([1, 2, 3, 4, 5, 6, 7, 8], buffer.read_slice(8).unwrap());
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(Test::A, buffer.read_be::<Test>().unwrap());
+    assert_eq!(Test::B, buffer.read_be::<Test>().unwrap());
+}
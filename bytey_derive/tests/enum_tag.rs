@@ -0,0 +1,157 @@
+use bytey::{ByteBuffer, ByteBufferError};
+use bytey_derive::{ByteBufferRead, ByteBufferWrite};
+
+#[test]
+fn test_enum_tag_u8() {
+    #[derive(ByteBufferWrite, ByteBufferRead, PartialEq, Debug)]
+    #[bytey(tag = "u8")]
+    enum Test {
+        A(u16),
+        B,
+    }
+
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write_be(Test::A(0x0102)).unwrap();
+    buffer.write_be(Test::B).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!([1, 1, 2, 2], buffer.read_slice(4).unwrap());
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(Test::A(0x0102), buffer.read_be::<Test>().unwrap());
+    assert_eq!(Test::B, buffer.read_be::<Test>().unwrap());
+}
+
+#[test]
+fn test_enum_tag_u32() {
+    #[derive(ByteBufferWrite, ByteBufferRead, PartialEq, Debug)]
+    #[bytey(tag = "u32")]
+    enum Test {
+        A,
+        #[bytey(id = 0x01020304)]
+        B,
+    }
+
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write_le(Test::A).unwrap();
+    buffer.write_be(Test::B).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!([1, 0, 0, 0, 1, 2, 3, 4], buffer.read_slice(8).unwrap());
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(Test::A, buffer.read_le::<Test>().unwrap());
+    assert_eq!(Test::B, buffer.read_be::<Test>().unwrap());
+}
+
+#[test]
+fn test_enum_tag_varint() {
+    #[derive(ByteBufferWrite, ByteBufferRead, PartialEq, Debug)]
+    #[bytey(tag = "varint")]
+    enum Test {
+        A(u8),
+        #[bytey(id = 300)]
+        B,
+        C,
+    }
+
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write(Test::A(7)).unwrap();
+    buffer.write(Test::B).unwrap();
+    buffer.write(Test::C).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        [
+            1, 7, /* id 1, u8: 7 */
+            0xAC, 0x02, /* id 300 */
+            0xAD, 0x02 /* id 301 */
+        ],
+        buffer.read_slice(6).unwrap()
+    );
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(Test::A(7), buffer.read::<Test>().unwrap());
+    assert_eq!(Test::B, buffer.read::<Test>().unwrap());
+    assert_eq!(Test::C, buffer.read::<Test>().unwrap());
+}
+
+#[test]
+fn test_enum_explicit_ids_survive_reordering() {
+    #[derive(ByteBufferWrite, ByteBufferRead, PartialEq, Debug)]
+    enum Old {
+        #[bytey(id = 10)]
+        Ping,
+        #[bytey(id = 20)]
+        Pong(u32),
+        #[bytey(id = 30)]
+        Removed,
+    }
+
+    #[derive(ByteBufferWrite, ByteBufferRead, PartialEq, Debug)]
+    enum New {
+        #[bytey(id = 20)]
+        Pong(u32),
+        #[bytey(id = 10)]
+        Ping,
+    }
+
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write(Old::Pong(5)).unwrap();
+    buffer.write(Old::Ping).unwrap();
+    buffer.write(Old::Removed).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(New::Pong(5), buffer.read::<New>().unwrap());
+    assert_eq!(New::Ping, buffer.read::<New>().unwrap());
+    assert!(buffer.read::<New>().is_err());
+}
+
+#[test]
+fn test_enum_implicit_ids_follow_explicit() {
+    #[derive(ByteBufferWrite, PartialEq, Debug)]
+    #[bytey(tag = "u8")]
+    enum Test {
+        A,
+        #[bytey(id = 5)]
+        B,
+        C,
+    }
+
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write(Test::A).unwrap();
+    buffer.write(Test::B).unwrap();
+    buffer.write(Test::C).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!([1, 5, 6], buffer.read_slice(3).unwrap());
+}
+
+#[test]
+fn test_varint_tag_overflow() {
+    #[derive(ByteBufferRead, PartialEq, Debug)]
+    #[bytey(tag = "varint")]
+    enum Test {
+        A,
+    }
+
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write_slice(&[0xFF, 0xFF, 0xFF, 0xFF, 0x1F]).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        buffer.read::<Test>().unwrap_err(),
+        ByteBufferError::VarIntOverflow {
+            type_name: "VarU32"
+        }
+    );
+}
@@ -0,0 +1,112 @@
+use bytey::{ByteBuffer, ByteBufferError, ByteBufferRef};
+use bytey_derive::{ByteBufferRead, ByteBufferReadRef, ByteBufferWrite};
+
+#[derive(ByteBufferWrite)]
+struct RawLogin {
+    id: u16,
+    credentials: RawCredentials,
+}
+
+#[derive(ByteBufferWrite)]
+struct RawCredentials {
+    user: String,
+    password: Vec<u32>,
+}
+
+#[derive(ByteBufferRead, ByteBufferReadRef, Debug)]
+struct Login {
+    #[allow(dead_code)]
+    id: u16,
+    #[allow(dead_code)]
+    credentials: Credentials,
+}
+
+#[derive(ByteBufferRead, ByteBufferReadRef, Debug)]
+struct Credentials {
+    #[allow(dead_code)]
+    user: String,
+    #[allow(dead_code)]
+    password: Vec<char>,
+}
+
+#[derive(ByteBufferWrite, ByteBufferRead, Debug)]
+enum Message {
+    #[allow(dead_code)]
+    Ping(u8, Option<u8>),
+}
+
+fn invalid_login() -> ByteBuffer {
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer
+        .write(RawLogin {
+            id: 1,
+            credentials: RawCredentials {
+                user: String::from("bob"),
+                // 0xD800 is a surrogate, which is not a valid char.
+                password: vec![b'a'.into(), b'b'.into(), b'c'.into(), 0xD800],
+            },
+        })
+        .unwrap();
+    buffer.move_cursor(0).unwrap();
+    buffer
+}
+
+#[test]
+fn test_field_path() {
+    let mut buffer = invalid_login();
+    let error = buffer.read::<Login>().unwrap_err();
+    let context = error.context().unwrap();
+
+    assert_eq!(error.root(), &ByteBufferError::NotAChar);
+    assert_eq!(context.path().to_string(), "Login.credentials.password[3]");
+    // The id, the user and the first 3 chars of the password, the invalid char has been read as well.
+    assert_eq!(context.offset(), Some(2 + 8 + 3 + 8 + 4 * 4));
+    assert_eq!(
+        error.to_string(),
+        format!(
+            "{} in Login.credentials.password[3] at byte 37",
+            ByteBufferError::NotAChar
+        )
+    );
+}
+
+#[test]
+fn test_field_path_read_ref() {
+    let buffer = invalid_login();
+    let mut reader: ByteBufferRef = buffer.reader();
+    let error = reader.read_ref::<Login>().unwrap_err();
+
+    assert_eq!(
+        error.context().unwrap().path().to_string(),
+        "Login.credentials.password[3]"
+    );
+}
+
+#[test]
+fn test_variant_path() {
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write(Message::Ping(7, None)).unwrap();
+
+    // Replace the tag of the option with one that doesn't exist.
+    let length = buffer.length();
+
+    buffer.truncate(length - 1).unwrap();
+    buffer.move_cursor_to_end().write(3u8).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    let error = buffer.read::<Message>().unwrap_err();
+
+    assert_eq!(
+        error.root(),
+        &ByteBufferError::InvalidTag {
+            type_name: "Option",
+            tag: 3
+        }
+    );
+    assert_eq!(
+        error.context().unwrap().path().to_string(),
+        "Message::Ping.1"
+    );
+}
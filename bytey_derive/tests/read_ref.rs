@@ -0,0 +1,213 @@
+use bytey::{ByteBuffer, ByteBufferRef};
+use bytey_derive::{ByteBufferRead, ByteBufferReadRef, ByteBufferWrite};
+use std::borrow::Cow;
+
+#[derive(ByteBufferWrite, ByteBufferRead, PartialEq, Debug)]
+struct Owned {
+    id: u16,
+    name: String,
+    payload: Vec<u8>,
+    tag: Option<String>,
+}
+
+#[derive(ByteBufferReadRef, PartialEq, Debug)]
+struct Borrowed<'a> {
+    id: u16,
+    name: &'a str,
+    payload: &'a [u8],
+    tag: Option<Cow<'a, str>>,
+}
+
+#[test]
+fn test_struct_read_ref() {
+    let val = Owned {
+        id: 0x0102,
+        name: String::from("bytey"),
+        payload: vec![1, 2, 3],
+        tag: Some(String::from("tag")),
+    };
+
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write_be(&val).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    let mut reader = buffer.reader();
+    let borrowed = reader.read_ref_be::<Borrowed>().unwrap();
+
+    assert_eq!(
+        borrowed,
+        Borrowed {
+            id: 0x0102,
+            name: "bytey",
+            payload: &[1, 2, 3],
+            tag: Some(Cow::Borrowed("tag")),
+        }
+    );
+    assert!(matches!(borrowed.tag, Some(Cow::Borrowed(_))));
+    assert_eq!(reader.cursor(), buffer.length());
+    assert_eq!(buffer.cursor(), 0);
+    assert_eq!(val, buffer.read_be::<Owned>().unwrap());
+}
+
+#[test]
+fn test_enum_read_ref() {
+    #[derive(ByteBufferWrite)]
+    enum Message {
+        Ping(u32),
+        Text { from: String, body: String },
+    }
+
+    #[derive(ByteBufferReadRef, PartialEq, Debug)]
+    enum MessageRef<'a> {
+        Ping(u32),
+        Text { from: &'a str, body: Cow<'a, str> },
+    }
+
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write_le(Message::Ping(7)).unwrap();
+    buffer
+        .write_le(Message::Text {
+            from: String::from("a"),
+            body: String::from("hello"),
+        })
+        .unwrap();
+
+    let bytes = {
+        buffer.move_cursor(0).unwrap();
+        buffer.read_slice(buffer.length()).unwrap().to_vec()
+    };
+    let mut reader = ByteBufferRef::new(&bytes);
+
+    assert_eq!(
+        reader.read_ref_le::<MessageRef>().unwrap(),
+        MessageRef::Ping(7)
+    );
+    assert_eq!(
+        reader.read_ref_le::<MessageRef>().unwrap(),
+        MessageRef::Text {
+            from: "a",
+            body: Cow::Borrowed("hello")
+        }
+    );
+}
+
+#[test]
+fn test_read_ref_without_lifetime() {
+    #[derive(ByteBufferWrite, ByteBufferReadRef, PartialEq, Debug)]
+    struct Test(u8, #[bytey(skip)] u32, bool);
+
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write(Test(1, 2, true)).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        buffer.reader().read_ref::<Test>().unwrap(),
+        Test(1, 0, true)
+    );
+}
+
+#[test]
+fn test_read_ref_out_of_bounds() {
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write(String::from("bytey")).unwrap();
+    buffer.truncate(10).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert!(buffer.reader().read_ref::<Borrowed>().is_err());
+    assert!(buffer.reader().read_ref::<&str>().is_err());
+}
+
+#[test]
+fn test_read_ref_owned_fields() {
+    use std::time::Duration;
+
+    #[derive(ByteBufferWrite)]
+    struct Header {
+        magic: [u8; 4],
+        version: (u8, u16),
+        timeout: Duration,
+        names: Vec<String>,
+        body: String,
+    }
+
+    #[derive(ByteBufferReadRef, PartialEq, Debug)]
+    struct HeaderRef<'a> {
+        magic: [u8; 4],
+        version: (u8, u16),
+        timeout: Duration,
+        names: Vec<&'a str>,
+        body: &'a str,
+    }
+
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer
+        .write(Header {
+            magic: *b"BYTE",
+            version: (1, 2),
+            timeout: Duration::from_millis(1500),
+            names: vec![String::from("a"), String::from("b")],
+            body: String::from("body"),
+        })
+        .unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        buffer.reader().read_ref::<HeaderRef>().unwrap(),
+        HeaderRef {
+            magic: *b"BYTE",
+            version: (1, 2),
+            timeout: Duration::from_millis(1500),
+            names: vec!["a", "b"],
+            body: "body",
+        }
+    );
+
+    // Owned values are read from the reader through `Source`.
+    let mut reader = buffer.reader();
+
+    assert_eq!(reader.read::<[u8; 4]>().unwrap(), *b"BYTE");
+    assert_eq!(reader.read::<(u8, u16)>().unwrap(), (1, 2));
+}
+
+#[test]
+fn test_read_ref_with() {
+    mod halved {
+        use bytey::{Sink, Source};
+
+        pub fn serialize<S: Sink>(value: &u8, buffer: &mut S) -> bytey::Result<()> {
+            buffer.write(value * 2)?;
+
+            Ok(())
+        }
+
+        pub fn deserialize<S: Source>(buffer: &mut S) -> bytey::Result<u8> {
+            Ok(buffer.read::<u8>()? / 2)
+        }
+    }
+
+    // The same module is used by the ByteBufferRead and the ByteBufferReadRef derive.
+    #[derive(ByteBufferWrite, ByteBufferRead, PartialEq, Debug)]
+    struct Test(#[bytey(with = "halved")] u8, String);
+
+    #[derive(ByteBufferReadRef, PartialEq, Debug)]
+    struct TestRef<'a>(#[bytey(with = "halved")] u8, &'a str);
+
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write(Test(21, String::from("bytey"))).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        buffer.reader().read_ref::<TestRef>().unwrap(),
+        TestRef(21, "bytey")
+    );
+    assert_eq!(
+        buffer.read::<Test>().unwrap(),
+        Test(21, String::from("bytey"))
+    );
+}
@@ -38,3 +38,45 @@ fn test_struct_unnamed_read() {
 
     assert_eq!(val, buffer.read::<Test>().unwrap());
 }
+
+#[test]
+fn test_struct_unit_read() {
+    #[derive(ByteBufferWrite, ByteBufferRead, PartialEq, Debug)]
+    struct Marker;
+
+    #[derive(ByteBufferWrite, ByteBufferRead, PartialEq, Debug)]
+    struct Test(u16, Marker, u8);
+
+    let mut buffer = ByteBuffer::new().unwrap();
+    let val = Test(128, Marker, 255);
+
+    buffer.write(&val).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(val, buffer.read::<Test>().unwrap());
+    assert_eq!(Marker, buffer.read::<Marker>().unwrap());
+}
+
+#[test]
+fn test_struct_collections_read() {
+    use std::collections::{BTreeSet, HashMap, VecDeque};
+
+    #[derive(ByteBufferWrite, ByteBufferRead, PartialEq, Debug)]
+    struct Test {
+        headers: HashMap<String, String>,
+        tags: BTreeSet<u16>,
+        queue: VecDeque<Option<u8>>,
+    }
+
+    let mut buffer = ByteBuffer::new().unwrap();
+    let val = Test {
+        headers: HashMap::from([(String::from("a"), String::from("b"))]),
+        tags: BTreeSet::from([3, 1]),
+        queue: VecDeque::from([Some(1), None]),
+    };
+
+    buffer.write(&val).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(val, buffer.read::<Test>().unwrap());
+}
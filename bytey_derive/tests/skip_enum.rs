@@ -0,0 +1,69 @@
+use bytey::ByteBuffer;
+use bytey_derive::{ByteBufferRead, ByteBufferWrite};
+
+#[test]
+fn test_enum_skip() {
+    #[derive(ByteBufferWrite, ByteBufferRead, PartialEq, Debug)]
+    #[bytey(tag = "u8")]
+    enum Test {
+        Named {
+            a: u16,
+            #[bytey(skip)]
+            b: i64,
+            c: u8,
+        },
+        Unnamed(u16, #[bytey(skip)] i64, u8),
+    }
+
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer
+        .write_be(Test::Named {
+            a: 0x0102,
+            b: -255,
+            c: 3,
+        })
+        .unwrap();
+    buffer.write_be(Test::Unnamed(0x0102, -255, 3)).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!([1, 1, 2, 3, 2, 1, 2, 3], buffer.read_slice(8).unwrap());
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        Test::Named {
+            a: 0x0102,
+            b: 0,
+            c: 3
+        },
+        buffer.read_be::<Test>().unwrap()
+    );
+    assert_eq!(
+        Test::Unnamed(0x0102, 0, 3),
+        buffer.read_be::<Test>().unwrap()
+    );
+}
+
+#[test]
+fn test_enum_skip_default() {
+    fn answer() -> String {
+        String::from("42")
+    }
+
+    #[derive(ByteBufferWrite, ByteBufferRead, PartialEq, Debug)]
+    enum Test {
+        A(#[bytey(skip, default = "answer")] String, u32),
+    }
+
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write(Test::A(String::from("ignored"), 9)).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        Test::A(String::from("42"), 9),
+        buffer.read::<Test>().unwrap()
+    );
+    assert_eq!(buffer.cursor(), buffer.length());
+}
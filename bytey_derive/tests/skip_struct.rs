@@ -40,3 +40,41 @@ fn test_struct_unnamed_skip() {
 
     assert_ne!(val, buffer.read::<Test>().unwrap());
 }
+
+#[test]
+fn test_struct_skip_default() {
+    #[derive(PartialEq, Debug)]
+    struct NoDefault(u32);
+
+    fn rebuild() -> NoDefault {
+        NoDefault(7)
+    }
+
+    #[derive(ByteBufferWrite, ByteBufferRead, PartialEq, Debug)]
+    struct Test {
+        a: u16,
+        #[bytey(skip, default = "rebuild")]
+        b: NoDefault,
+        c: u8,
+    }
+
+    let mut buffer = ByteBuffer::new().unwrap();
+    let val = Test {
+        a: 128,
+        b: NoDefault(1),
+        c: 255,
+    };
+
+    buffer.write(&val).unwrap();
+    assert_eq!(buffer.length(), 3);
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        Test {
+            a: 128,
+            b: NoDefault(7),
+            c: 255,
+        },
+        buffer.read::<Test>().unwrap()
+    );
+}
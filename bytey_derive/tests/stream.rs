@@ -0,0 +1,42 @@
+use bytey::{ByteBuffer, IoSink, IoSource, Sink, Source};
+use bytey_derive::{ByteBufferRead, ByteBufferWrite};
+
+#[derive(ByteBufferWrite, ByteBufferRead, PartialEq, Debug)]
+#[bytey(tag = "u8")]
+enum Command {
+    Ping,
+    Say { text: String },
+}
+
+#[derive(ByteBufferWrite, ByteBufferRead, PartialEq, Debug)]
+struct Message {
+    #[bytey(endian = "be")]
+    id: u32,
+    commands: Vec<Command>,
+}
+
+#[test]
+fn test_derive_stream() {
+    let message = Message {
+        id: 7,
+        commands: vec![
+            Command::Ping,
+            Command::Say {
+                text: String::from("hello"),
+            },
+        ],
+    };
+
+    let mut sink = IoSink::new(Vec::new());
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    sink.write_le(&message).unwrap();
+    buffer.write_le(&message).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    let bytes = sink.into_inner();
+    assert_eq!(buffer.read_slice(buffer.length()).unwrap(), &bytes[..]);
+
+    let mut source = IoSource::new(&bytes[..]);
+    assert_eq!(source.read_le::<Message>().unwrap(), message);
+}
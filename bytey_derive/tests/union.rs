@@ -0,0 +1,40 @@
+use bytey::ByteBuffer;
+use bytey_derive::{ByteBufferRead, ByteBufferWrite};
+
+#[derive(ByteBufferWrite, ByteBufferRead, Clone, Copy)]
+#[bytey(union_as = "bytes")]
+union Test {
+    int: u32,
+    float: f32,
+    bytes: [u8; 4],
+}
+
+#[test]
+fn test_union_write() {
+    let mut buffer = ByteBuffer::new().unwrap();
+    let val = Test { int: 0x01020304 };
+
+    buffer.write(val).unwrap();
+    buffer.write_be(val).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.length(), 8);
+    assert_eq!(0x01020304u32.to_ne_bytes(), buffer.read_slice(4).unwrap());
+    assert_eq!(0x01020304u32.to_ne_bytes(), buffer.read_slice(4).unwrap());
+}
+
+#[test]
+fn test_union_read() {
+    let mut buffer = ByteBuffer::new().unwrap();
+    let val = Test { float: 1.5 };
+
+    buffer.write(val).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    let read = buffer.read::<Test>().unwrap();
+
+    unsafe {
+        assert_eq!(read.float, 1.5);
+        assert_eq!(read.bytes, 1.5f32.to_ne_bytes());
+    }
+}
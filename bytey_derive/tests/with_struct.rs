@@ -0,0 +1,120 @@
+use bytey::{ByteBuffer, Sink, Source};
+use bytey_derive::{ByteBufferRead, ByteBufferWrite};
+
+/// Stands in for a foreign type that implements neither bytey trait.
+#[derive(PartialEq, Debug, Clone, Copy)]
+struct Uuid([u8; 4]);
+
+mod uuid_bytes {
+    use super::Uuid;
+    use bytey::{Sink, Source};
+
+    pub fn serialize<S: Sink>(value: &Uuid, buffer: &mut S) -> bytey::Result<()> {
+        buffer.write_slice(&value.0)?;
+
+        Ok(())
+    }
+
+    pub fn deserialize<S: Source>(buffer: &mut S) -> bytey::Result<Uuid> {
+        let bytes = buffer.read_slice(4)?;
+
+        Ok(Uuid([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+}
+
+fn write_doubled<S: Sink>(value: &u8, buffer: &mut S) -> bytey::Result<()> {
+    buffer.write(value * 2)?;
+
+    Ok(())
+}
+
+fn read_halved<S: Source>(buffer: &mut S) -> bytey::Result<u8> {
+    Ok(buffer.read::<u8>()? / 2)
+}
+
+#[test]
+fn test_struct_with() {
+    #[derive(ByteBufferWrite, ByteBufferRead, PartialEq, Debug)]
+    struct Test {
+        a: u16,
+        #[bytey(with = "uuid_bytes")]
+        id: Uuid,
+        b: u8,
+    }
+
+    let val = Test {
+        a: 0x0102,
+        id: Uuid([5, 6, 7, 8]),
+        b: 9,
+    };
+
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write_be(&val).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!([1, 2, 5, 6, 7, 8, 9], buffer.read_slice(7).unwrap());
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(val, buffer.read_be::<Test>().unwrap());
+}
+
+#[test]
+fn test_struct_unnamed_serialize_with() {
+    #[derive(ByteBufferWrite, ByteBufferRead, PartialEq, Debug)]
+    struct Test(
+        #[bytey(serialize_with = "write_doubled", deserialize_with = "read_halved")] u8,
+        #[bytey(with = "uuid_bytes")] Uuid,
+    );
+
+    let val = Test(21, Uuid([1, 2, 3, 4]));
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write(&val).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!([42, 1, 2, 3, 4], buffer.read_slice(5).unwrap());
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(val, buffer.read::<Test>().unwrap());
+}
+
+#[test]
+fn test_enum_with() {
+    #[derive(ByteBufferWrite, ByteBufferRead, PartialEq, Debug)]
+    #[bytey(tag = "u8")]
+    enum Test {
+        Named {
+            #[bytey(with = "uuid_bytes")]
+            id: Uuid,
+        },
+        Unnamed(
+            u8,
+            #[bytey(serialize_with = "write_doubled", deserialize_with = "read_halved")] u8,
+        ),
+    }
+
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer
+        .write_le(Test::Named {
+            id: Uuid([1, 2, 3, 4]),
+        })
+        .unwrap();
+    buffer.write_le(Test::Unnamed(3, 4)).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!([1, 1, 2, 3, 4, 2, 3, 8], buffer.read_slice(8).unwrap());
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        Test::Named {
+            id: Uuid([1, 2, 3, 4])
+        },
+        buffer.read_le::<Test>().unwrap()
+    );
+    assert_eq!(Test::Unnamed(3, 4), buffer.read_le::<Test>().unwrap());
+}
@@ -54,3 +54,17 @@ fn test_struct_unnamed_write() {
         buffer.read_slice(19).unwrap()
     );
 }
+
+#[test]
+fn test_struct_unit_write() {
+    #[derive(ByteBufferWrite)]
+    struct Marker;
+
+    let mut buffer = ByteBuffer::new().unwrap();
+
+    buffer.write(Marker).unwrap();
+    buffer.write_le(Marker).unwrap();
+    buffer.write_be(&Marker).unwrap();
+
+    assert_eq!(buffer.length(), 0);
+}